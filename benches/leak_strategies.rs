@@ -0,0 +1,57 @@
+//! benches/leak_strategies.rs
+//!
+//! Compares the leak strategies across input sizes and string lengths.
+//! Throughput is reported in elements per second, so the cost per string
+//! can be compared directly across input sizes. Run with `cargo bench`.
+//!
+//! Every iteration leaks its output, so the largest inputs use a small
+//! sample size to keep the memory growth of a run bounded.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use vec_string_to_static_str::{
+    intern_static_strs, leak_vec_as_static_strs, vec_string_to_static_str,
+    vec_string_to_static_str_packed,
+};
+
+const SIZES: [usize; 3] = [10, 1_000, 100_000];
+const LENGTHS: [(&str, usize); 2] = [("short", 8), ("long", 256)];
+
+fn input(size: usize, len: usize) -> Vec<String> {
+    (0..size)
+        .map(|i| {
+            let mut string = format!("{i:0len$}");
+            string.truncate(len);
+            string
+        })
+        .collect()
+}
+
+fn leak_strategies(c: &mut Criterion) {
+    for (name, len) in LENGTHS {
+        let mut group = c.benchmark_group(format!("leak_strategies/{name}"));
+
+        for size in SIZES {
+            let strings = input(size, len);
+            group.throughput(Throughput::Elements(size as u64));
+            group.sample_size(if size >= 100_000 { 10 } else { 50 });
+
+            group.bench_with_input(BenchmarkId::new("box_leak", size), &strings, |b, s| {
+                b.iter(|| vec_string_to_static_str(s))
+            });
+            group.bench_with_input(BenchmarkId::new("packed", size), &strings, |b, s| {
+                b.iter(|| vec_string_to_static_str_packed(s))
+            });
+            group.bench_with_input(BenchmarkId::new("vec_leak", size), &strings, |b, s| {
+                b.iter_batched(|| s.clone(), leak_vec_as_static_strs, BatchSize::LargeInput)
+            });
+            group.bench_with_input(BenchmarkId::new("intern", size), &strings, |b, s| {
+                b.iter(|| intern_static_strs(s))
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, leak_strategies);
+criterion_main!(benches);
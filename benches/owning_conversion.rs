@@ -0,0 +1,84 @@
+//! benches/owning_conversion.rs
+//!
+//! Measures the consuming `into_static_strs` against the copying
+//! `vec_string_to_static_str`. Before timing, the number of allocations
+//! each converter makes for one input is printed, which shows that the
+//! consuming path allocates only the output vector, without copying any
+//! strings.
+//! Run with `cargo bench --bench owning_conversion`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use vec_string_to_static_str::{into_static_strs, vec_string_to_static_str};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const SIZES: [usize; 3] = [10, 1_000, 100_000];
+
+fn input(size: usize) -> Vec<String> {
+    (0..size).map(|i| format!("string_{i}")).collect()
+}
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(result);
+
+    after - before
+}
+
+fn owning_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("owning_conversion");
+
+    for size in SIZES {
+        let strings = input(size);
+        let owned = strings.clone();
+        println!(
+            "owning_conversion/{size}: into_static_strs made {} allocations, \
+             vec_string_to_static_str made {}",
+            count_allocations(|| into_static_strs(owned)),
+            count_allocations(|| vec_string_to_static_str(&strings)),
+        );
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.sample_size(if size >= 100_000 { 10 } else { 50 });
+
+        group.bench_with_input(
+            BenchmarkId::new("into_static_strs", size),
+            &strings,
+            |b, s| b.iter_batched(|| s.clone(), into_static_strs, BatchSize::LargeInput),
+        );
+        group.bench_with_input(BenchmarkId::new("copy", size), &strings, |b, s| {
+            b.iter(|| vec_string_to_static_str(s))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, owning_conversion);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+//! fuzz/fuzz_targets/arena.rs
+//!
+//! Pushes arbitrary strings into a `LeakArena` with an arbitrary chunk
+//! size and checks that no pushed string is overwritten later.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vec_string_to_static_str::*;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&chunk_size, rest)) = data.split_first() else {
+        return;
+    };
+    let strings: Vec<String> = rest
+        .split(|&byte| byte == 0)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect();
+
+    let mut arena = LeakArena::with_capacity(usize::from(chunk_size));
+    let pushed: Vec<&'static str> = strings.iter().map(|string| arena.push(string)).collect();
+    assert_eq!(strings, pushed);
+
+    // SAFETY: no leaked string outlives this iteration, so freeing them all
+    // keeps the fuzzer's memory bounded.
+    unsafe { free_all_leaked() };
+});
@@ -0,0 +1,40 @@
+//! fuzz/fuzz_targets/convert.rs
+//!
+//! Feeds arbitrary strings through the per-string conversions and checks
+//! that every output matches its input.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vec_string_to_static_str::*;
+
+fuzz_target!(|data: &[u8]| {
+    // NUL bytes separate the strings, so inputs can contain empty strings
+    // and strings split in the middle of a UTF-8 character.
+    let strings: Vec<String> = data
+        .split(|&byte| byte == 0)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect();
+
+    assert_eq!(strings, vec_string_to_static_str(&strings));
+    assert_eq!(strings, vec_string_to_static_slice(&strings));
+    assert_eq!(strings, to_static_strs(&strings));
+    assert_eq!(strings, intern_static_strs(&strings));
+    assert_eq!(strings, into_static_strs(strings.clone()));
+    assert_eq!(strings, leak_vec_as_static_strs(strings.clone()));
+
+    let mut expected: Vec<&str> = strings.iter().map(String::as_str).collect();
+    expected.sort_unstable();
+    expected.dedup();
+    assert_eq!(expected, leak_sorted_dedup(&strings));
+
+    let deduped = leak_dedup_preserve_order(&strings);
+    assert_eq!(expected.len(), deduped.len());
+
+    let bytes: Vec<Vec<u8>> = strings.iter().map(|string| string.clone().into_bytes()).collect();
+    assert_eq!(strings, vec_bytes_to_static_str(&bytes).unwrap());
+
+    // SAFETY: no leaked string outlives this iteration, so freeing them all
+    // keeps the fuzzer's memory bounded.
+    unsafe { free_all_leaked() };
+});
@@ -0,0 +1,36 @@
+//! fuzz/fuzz_targets/packed.rs
+//!
+//! Feeds arbitrary strings through the conversions that compute offsets
+//! into a shared buffer and checks that every span round-trips.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vec_string_to_static_str::*;
+
+fuzz_target!(|data: &[u8]| {
+    let text = String::from_utf8_lossy(data);
+    let strings: Vec<String> = text.split('\0').map(String::from).collect();
+
+    assert_eq!(strings, vec_string_to_static_str_packed(&strings));
+
+    let (strs, spans) = vec_string_to_static_str_with_spans(&strings);
+    let buffer = strings.concat();
+    assert_eq!(strings, strs);
+    for (string, span) in strings.iter().zip(spans) {
+        assert_eq!(string.as_str(), &buffer[span]);
+    }
+
+    let (strs, raw_parts) = leak_to_raw_parts_with_strs(&strings);
+    for (s, (ptr, len)) in strs.iter().zip(raw_parts) {
+        assert_eq!((s.as_ptr(), s.len()), (ptr, len));
+    }
+
+    let joined = leak_join(&strings, "\0");
+    assert_eq!(text, joined);
+    assert_eq!(strings, split_to_static_strs(&text, '\0'));
+
+    // SAFETY: no leaked string outlives this iteration, so freeing them all
+    // keeps the fuzzer's memory bounded.
+    unsafe { free_all_leaked() };
+});
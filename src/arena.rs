@@ -0,0 +1,177 @@
+//! src/arena.rs
+
+use alloc::vec;
+use core::fmt;
+
+/// A bump allocator that copies strings into large leaked buffers.
+///
+/// An arena leaks memory in chunks rather than per string: each chunk is a
+/// single leaked allocation, and pushed strings are copied into the free
+/// space at its end. When a string does not fit, a new chunk is leaked and
+/// the unused tail of the previous one is abandoned. This amortizes the
+/// allocation cost across many small strings.
+///
+/// With the `metrics` feature enabled, whole chunks are counted towards
+/// `leaked_bytes` when they are allocated, while each non-empty pushed
+/// string counts towards `leaked_count`. Likewise, with the
+/// `debug-leak-sites` feature enabled, each chunk is recorded in
+/// `leak_sites` under the call that allocated it.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::LeakArena;
+///
+/// let mut arena = LeakArena::with_capacity(1024);
+/// let hello = arena.push("hello");
+/// let world = arena.push("world");
+/// assert_eq!([hello, world], ["hello", "world"]);
+/// ```
+///
+/// Its `Debug` output shows the chunk size and how many bytes are left in
+/// the current chunk, never the chunk contents.
+pub struct LeakArena {
+    chunk_size: usize,
+    free: &'static mut [u8],
+}
+
+impl LeakArena {
+    /// Creates an arena whose chunks hold `bytes` bytes each, leaking the
+    /// first chunk up front.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The size of each leaked chunk.
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn with_capacity(bytes: usize) -> Self {
+        Self {
+            chunk_size: bytes,
+            free: leak_chunk(bytes),
+        }
+    }
+
+    /// Copies a string slice into the arena.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to be copied.
+    ///
+    /// # Returns
+    ///
+    /// A `&'static str` reference to the copy inside the arena.
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn push(&mut self, s: &str) -> &'static str {
+        if s.is_empty() {
+            return "";
+        }
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_leak(1, 0);
+
+        if s.len() > self.free.len() {
+            if s.len() > self.chunk_size {
+                // Too big for any chunk, so give it a dedicated one and keep
+                // filling the current chunk afterwards.
+                return copy_str(leak_chunk(s.len()), s);
+            }
+
+            self.free = leak_chunk(self.chunk_size);
+        }
+
+        let free = core::mem::take(&mut self.free);
+        let (used, free) = free.split_at_mut(s.len());
+        self.free = free;
+
+        copy_str(used, s)
+    }
+}
+
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+fn leak_chunk(bytes: usize) -> &'static mut [u8] {
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_leak(0, bytes);
+    crate::Caller::here().record(bytes);
+
+    crate::leak_unmetered(vec![0; bytes].into_boxed_slice())
+}
+
+impl fmt::Debug for LeakArena {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LeakArena")
+            .field("chunk_size", &self.chunk_size)
+            .field("free", &self.free.len())
+            .finish()
+    }
+}
+
+fn copy_str(dst: &'static mut [u8], s: &str) -> &'static str {
+    dst.copy_from_slice(s.as_bytes());
+
+    // SAFETY: `dst` now holds an exact copy of the bytes of a `str`.
+    unsafe { core::str::from_utf8_unchecked(dst) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn leak_arena_push_returns_content() {
+        let mut arena = LeakArena::with_capacity(64);
+
+        let actual = [arena.push("string_a"), arena.push(""), arena.push("你好")];
+
+        assert_eq!(["string_a", "", "你好"], actual);
+    }
+
+    #[test]
+    fn leak_arena_packs_strings_into_chunks() {
+        let strings: Vec<String> = (0..1000).map(|i| format!("string_{i}")).collect();
+        let mut arena = LeakArena::with_capacity(64);
+
+        let (actual, allocations) = count_allocations(|| {
+            let mut strs = Vec::with_capacity(strings.len());
+            for string in &strings {
+                strs.push(arena.push(string));
+            }
+            strs
+        });
+
+        assert_eq!(strings, actual);
+        // One allocation for the output plus one per 64 byte chunk.
+        assert!(allocations < strings.len() / 4);
+    }
+
+    #[test]
+    fn leak_arena_oversized_string_keeps_current_chunk() {
+        let mut arena = LeakArena::with_capacity(8);
+        let first = arena.push("abc");
+
+        let big = arena.push("a string longer than one chunk");
+        let second = arena.push("def");
+
+        assert_eq!("a string longer than one chunk", big);
+        assert_eq!(first.as_ptr() as usize + 3, second.as_ptr() as usize);
+    }
+
+    #[test]
+    fn leak_arena_zero_capacity() {
+        let mut arena = LeakArena::with_capacity(0);
+
+        let actual = [arena.push("string_a"), arena.push("string_b")];
+
+        assert_eq!(["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn leak_arena_debug_omits_chunk_contents() {
+        let mut arena = LeakArena::with_capacity(1024);
+        let _ = arena.push("string_a");
+
+        let actual = format!("{arena:?}");
+
+        assert_eq!("LeakArena { chunk_size: 1024, free: 1016 }", actual);
+    }
+}
@@ -0,0 +1,91 @@
+//! src/boxed.rs
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Converts a vector of `String`s into a vector of owned `Box<str>` without
+/// leaking.
+///
+/// This is the non-leaking alternative for callers that only need compact,
+/// immutable strings, for example to store in a struct: each `Box<str>`
+/// holds exactly its bytes, without the spare capacity a `String` may
+/// carry, and is freed as usual when dropped.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `Box<str>` holding copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_boxed_str;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let boxed = vec_string_to_boxed_str(&strings);
+/// assert_eq!(&*boxed[0], "hello");
+/// ```
+pub fn vec_string_to_boxed_str(strings: &[String]) -> Vec<Box<str>> {
+    let mut boxed: Vec<Box<str>> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        boxed.push(Box::from(string.as_str()));
+    }
+
+    boxed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::{count_allocations, count_deallocations};
+
+    #[test]
+    fn vec_string_to_boxed_str_copies_content() {
+        let strings = vec!["string_a".to_string(), "你好".to_string()];
+
+        let actual = vec_string_to_boxed_str(&strings);
+
+        assert_eq!(strings.len(), actual.len());
+        for (string, boxed) in strings.iter().zip(&actual) {
+            assert_eq!(string.as_str(), &**boxed);
+            assert_ne!(string.as_ptr(), boxed.as_ptr());
+        }
+    }
+
+    #[test]
+    fn vec_string_to_boxed_str_empty_string() {
+        let strings = vec!["".to_string()];
+
+        let actual = vec_string_to_boxed_str(&strings);
+
+        assert_eq!("", &*actual[0]);
+    }
+
+    #[test]
+    fn vec_string_to_boxed_str_is_freed_when_dropped() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let (actual, allocations) = count_allocations(|| vec_string_to_boxed_str(&strings));
+        let ((), deallocations) = count_deallocations(|| drop(actual));
+
+        // The two strings and the vector holding them.
+        assert_eq!(3, allocations);
+        assert_eq!(allocations, deallocations);
+    }
+
+    #[test]
+    fn vec_string_to_boxed_str_drops_spare_capacity() {
+        let mut string = String::with_capacity(100);
+        string.push_str("hi");
+
+        let actual = vec_string_to_boxed_str(&[string]);
+
+        assert_eq!("hi", &*actual[0]);
+        assert_eq!(2, actual[0].len());
+    }
+}
@@ -0,0 +1,158 @@
+//! src/bytes.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::Utf8Error;
+
+use crate::{leak_bytes, str_to_static_str};
+
+/// Converts a vector of `String`s into a vector of `&'static [u8]`.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// byte slices from copies of the input strings' bytes. Use with caution.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static [u8]` references to copies of the input strings'
+/// bytes.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_bytes;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_bytes = vec_string_to_static_bytes(&strings);
+/// assert_eq!(static_bytes, vec![b"hello", b"world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_bytes(strings: &[String]) -> Vec<&'static [u8]> {
+    let mut bytes: Vec<&'static [u8]> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        bytes.push(leak_bytes(string.clone().into_bytes().into_boxed_slice()));
+    }
+
+    bytes
+}
+
+/// Converts a vector of byte vectors into a vector of `&'static str`,
+/// validating that each one is UTF-8.
+///
+/// Every chunk is validated with `core::str::from_utf8` before anything is
+/// leaked, so if one of them is invalid its index and error are returned
+/// and no memory is leaked at all. Valid chunks are then copied and leaked
+/// with `Box::leak`.
+///
+/// # Arguments
+///
+/// * `chunks` - A slice of byte vectors to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the chunks, or the
+/// index and `Utf8Error` of the first chunk that is not valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_bytes_to_static_str;
+///
+/// let chunks = vec![b"hello".to_vec(), b"world".to_vec()];
+/// let static_strs = vec_bytes_to_static_str(&chunks).unwrap();
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+///
+/// let invalid = vec![b"hello".to_vec(), vec![0xff]];
+/// assert_eq!(vec_bytes_to_static_str(&invalid).unwrap_err().0, 1);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_bytes_to_static_str(
+    chunks: &[Vec<u8>],
+) -> Result<Vec<&'static str>, (usize, Utf8Error)> {
+    let mut validated: Vec<&str> = Vec::with_capacity(chunks.len());
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        validated.push(core::str::from_utf8(chunk).map_err(|error| (index, error))?);
+    }
+
+    let mut strs: Vec<&'static str> = Vec::with_capacity(validated.len());
+
+    for s in validated {
+        strs.push(str_to_static_str(s));
+    }
+
+    Ok(strs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn vec_string_to_static_bytes_from_dynamic_allocation() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = vec_string_to_static_bytes(&strings);
+
+        assert_eq!(vec![b"string_a", b"string_b"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_bytes_empty_string() {
+        let strings = vec![String::new()];
+
+        let actual = vec_string_to_static_bytes(&strings);
+
+        assert_eq!(vec![b""], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_bytes_multibyte() {
+        let strings = vec!["你好".to_string(), "é".to_string()];
+
+        let actual = vec_string_to_static_bytes(&strings);
+
+        assert_eq!(
+            vec![&[0xe4, 0xbd, 0xa0, 0xe5, 0xa5, 0xbd][..], &[0xc3, 0xa9][..]],
+            actual
+        );
+    }
+
+    #[test]
+    fn vec_string_to_static_bytes_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = vec_string_to_static_bytes(&strings);
+
+        assert_eq!(Vec::<&'static [u8]>::new(), actual);
+    }
+
+    #[test]
+    fn vec_bytes_to_static_str_all_valid() {
+        let chunks = vec![b"string_a".to_vec(), Vec::new(), "你好".as_bytes().to_vec()];
+
+        let actual = vec_bytes_to_static_str(&chunks);
+
+        assert_eq!(Ok(vec!["string_a", "", "你好"]), actual);
+    }
+
+    #[test]
+    fn vec_bytes_to_static_str_invalid_chunk_in_middle() {
+        let chunks = vec![b"string_a".to_vec(), b"string\xffb".to_vec(), vec![0xc3]];
+
+        let (actual, allocations) = count_allocations(|| vec_bytes_to_static_str(&chunks));
+
+        let (index, error) = actual.unwrap_err();
+        assert_eq!(1, index);
+        assert_eq!(6, error.valid_up_to());
+        // Only the scratch vector of validated chunks is allocated.
+        assert_eq!(1, allocations);
+    }
+}
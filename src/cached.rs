@@ -0,0 +1,155 @@
+//! src/cached.rs
+
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+use hashbrown::HashTable;
+
+use crate::vec_string_to_static_str;
+
+/// A converter that remembers the tables it has leaked and reuses them for
+/// identical inputs.
+///
+/// Converting the same `Vec<String>` repeatedly, for example in a hot loop,
+/// would normally leak a fresh copy of every string on each call. A
+/// `CachedConverter` hashes each input instead, and returns the previously
+/// leaked strings when it has already seen equal content, so each distinct
+/// input is only leaked once. Inputs only match when they contain the same
+/// strings in the same order.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::CachedConverter;
+///
+/// let mut converter = CachedConverter::new();
+/// let strings = vec![String::from("hello"), String::from("world")];
+///
+/// let first = converter.convert(&strings);
+/// let second = converter.convert(&strings);
+/// assert!(std::ptr::eq(first[0], second[0]));
+/// assert_eq!(converter.len(), 1);
+/// ```
+#[derive(Default)]
+pub struct CachedConverter {
+    tables: HashTable<Vec<&'static str>>,
+    hash_builder: RandomState,
+}
+
+impl CachedConverter {
+    /// Creates a converter with an empty cache.
+    pub fn new() -> Self {
+        Self {
+            tables: HashTable::new(),
+            hash_builder: RandomState::new(),
+        }
+    }
+
+    /// Converts a vector of `String`s into a vector of `&'static str`,
+    /// leaking it only if equal content has not been converted before.
+    ///
+    /// # Arguments
+    ///
+    /// * `strings` - A slice of `String`s to be converted.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `&'static str` references that are pointer-identical,
+    /// element by element, for every call with equal content.
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn convert(&mut self, strings: &[String]) -> Vec<&'static str> {
+        // `String` and `&str` hash like `str`, so a slice of either hashes
+        // identically for equal content.
+        let hash = self.hash_builder.hash_one(strings);
+        let eq = |table: &Vec<&'static str>| table.iter().eq(strings);
+
+        if let Some(table) = self.tables.find(hash, eq) {
+            return table.clone();
+        }
+
+        let table = vec_string_to_static_str(strings);
+        let hash_builder = &self.hash_builder;
+        self.tables
+            .insert_unique(hash, table.clone(), |table| hash_builder.hash_one(table));
+
+        table
+    }
+
+    /// Returns the number of distinct inputs that have been leaked.
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Returns `true` if nothing has been converted yet.
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn cached_converter_new_is_empty() {
+        let converter = CachedConverter::new();
+
+        assert!(converter.is_empty());
+        assert_eq!(0, converter.len());
+    }
+
+    #[test]
+    fn cached_converter_convert_same_input_twice() {
+        let mut converter = CachedConverter::new();
+        let strings = vec!["string_a".to_string(), "".to_string(), "你好".to_string()];
+
+        let first = converter.convert(&strings);
+        let second = converter.convert(&strings.clone());
+
+        assert_eq!(strings, first);
+        assert_eq!(1, converter.len());
+        for (first, second) in first.iter().zip(&second) {
+            assert!(std::ptr::eq(*first, *second));
+        }
+    }
+
+    #[test]
+    fn cached_converter_convert_cached_input_leaks_nothing() {
+        let mut converter = CachedConverter::new();
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+        let _ = converter.convert(&strings);
+
+        let (actual, allocations) = count_allocations(|| converter.convert(&strings));
+
+        assert_eq!(strings, actual);
+        // Only the returned vector is allocated.
+        assert_eq!(1, allocations);
+    }
+
+    #[test]
+    fn cached_converter_convert_distinct_inputs() {
+        let mut converter = CachedConverter::new();
+        let forward = vec!["string_a".to_string(), "string_b".to_string()];
+        let reversed = vec!["string_b".to_string(), "string_a".to_string()];
+
+        let first = converter.convert(&forward);
+        let second = converter.convert(&reversed);
+
+        assert_eq!(forward, first);
+        assert_eq!(reversed, second);
+        assert_eq!(2, converter.len());
+        assert!(!std::ptr::eq(first[0], second[1]));
+    }
+
+    #[test]
+    fn cached_converter_convert_empty_vector() {
+        let mut converter = CachedConverter::new();
+
+        let actual = converter.convert(&[]);
+
+        assert!(actual.is_empty());
+        assert_eq!(1, converter.len());
+    }
+}
@@ -0,0 +1,109 @@
+//! src/chunks.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::str_to_static_str;
+
+/// Converts a vector of `String`s into `&'static str`s one chunk at a time,
+/// passing each converted chunk to a callback.
+///
+/// Every string is still leaked with `Box::leak`, but only `chunk_size`
+/// references are kept alive at once: a single buffer is reused for every
+/// chunk, so the peak memory of the output no longer grows with the input.
+/// The last chunk may be shorter than `chunk_size`, and the callback is not
+/// called at all for an empty input.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+/// * `chunk_size` - The maximum number of strings passed to each call to `f`.
+/// * `f` - The callback receiving each converted chunk, in order.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is 0, like [`slice::chunks`].
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::for_each_static_chunk;
+///
+/// let strings: Vec<String> = (1..=5).map(|i| i.to_string()).collect();
+/// let mut chunks = Vec::new();
+/// for_each_static_chunk(&strings, 2, |chunk| chunks.push(chunk.to_vec()));
+/// assert_eq!(chunks, vec![vec!["1", "2"], vec!["3", "4"], vec!["5"]]);
+/// ```
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn for_each_static_chunk<F: FnMut(&[&'static str])>(
+    strings: &[String],
+    chunk_size: usize,
+    mut f: F,
+) {
+    assert!(chunk_size != 0, "chunk size must be non-zero");
+
+    let mut strs: Vec<&'static str> = Vec::with_capacity(chunk_size.min(strings.len()));
+
+    for chunk in strings.chunks(chunk_size) {
+        strs.clear();
+        for string in chunk {
+            strs.push(str_to_static_str(string));
+        }
+        f(&strs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+    use crate::vec_string_to_static_str;
+
+    #[test]
+    fn for_each_static_chunk_matches_straight_conversion() {
+        let strings: Vec<String> = (0..100).map(|i| format!("string_{i}")).collect();
+        let mut actual: Vec<&'static str> = Vec::new();
+
+        for_each_static_chunk(&strings, 7, |chunk| actual.extend_from_slice(chunk));
+
+        assert_eq!(vec_string_to_static_str(&strings), actual);
+    }
+
+    #[test]
+    fn for_each_static_chunk_bounds_chunk_length() {
+        let strings: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        let mut lengths: Vec<usize> = Vec::new();
+
+        for_each_static_chunk(&strings, 4, |chunk| lengths.push(chunk.len()));
+
+        assert_eq!(vec![4, 4, 2], lengths);
+    }
+
+    #[test]
+    fn for_each_static_chunk_reuses_output_buffer() {
+        let strings: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+
+        let ((), allocations) = count_allocations(|| for_each_static_chunk(&strings, 3, |_| {}));
+
+        // One buffer for all chunks and one allocation per string.
+        assert_eq!(1 + strings.len(), allocations);
+    }
+
+    #[test]
+    fn for_each_static_chunk_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+        let mut calls = 0;
+
+        for_each_static_chunk(&strings, 3, |_| calls += 1);
+
+        assert_eq!(0, calls);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn for_each_static_chunk_zero_chunk_size() {
+        let strings = vec!["string_a".to_string()];
+
+        for_each_static_chunk(&strings, 0, |_| {});
+    }
+}
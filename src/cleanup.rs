@@ -0,0 +1,168 @@
+//! src/cleanup.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::ptr::NonNull;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// An allocation leaked by this crate, as needed to free it again.
+struct Leaked {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+// SAFETY: the registry never reads through the pointers; it only frees
+// them, which the global allocator allows from any thread.
+unsafe impl Send for Leaked {}
+
+static LEAKED: Mutex<Vec<Leaked>> = Mutex::new(Vec::new());
+
+fn leaked() -> MutexGuard<'static, Vec<Leaked>> {
+    LEAKED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Records a leaked box so that [`free_all_leaked`] can free it.
+///
+/// # Safety
+///
+/// `ptr` must come from `Box::into_raw` and must not be freed by anything
+/// other than this registry, except through [`free`].
+pub(crate) unsafe fn register<T: ?Sized>(ptr: *mut T) {
+    // SAFETY: the caller guarantees that `ptr` points to a live box.
+    let layout = Layout::for_value(unsafe { &*ptr });
+    register_raw(ptr.cast(), layout);
+}
+
+/// Records the buffers of a vector of strings that is about to be leaked
+/// with `Vec::leak`, so that [`free_all_leaked`] can free them.
+pub(crate) fn register_strings(strings: &mut Vec<String>) {
+    for string in strings.iter_mut() {
+        let capacity = string.capacity();
+        // SAFETY: the bytes are only accessed through the raw pointer,
+        // without being modified.
+        let ptr = unsafe { string.as_mut_vec() }.as_mut_ptr();
+        register_raw(
+            ptr,
+            Layout::array::<u8>(capacity).expect("valid string layout"),
+        );
+    }
+
+    let layout = Layout::array::<String>(strings.capacity()).expect("valid vector layout");
+    register_raw(strings.as_mut_ptr().cast(), layout);
+}
+
+fn register_raw(ptr: *mut u8, layout: Layout) {
+    // Zero-sized boxes own no allocation, so there is nothing to free.
+    if layout.size() == 0 {
+        return;
+    }
+
+    if let Some(ptr) = NonNull::new(ptr) {
+        let allocation = Leaked { ptr, layout };
+
+        // Growing the registry allocates, which would throw off the unit
+        // tests that count the allocations of the leaking functions.
+        #[cfg(test)]
+        crate::test_alloc::uncounted(|| leaked().push(allocation));
+        #[cfg(not(test))]
+        leaked().push(allocation);
+    }
+}
+
+/// Frees the registered allocation of `len` bytes starting at `ptr`,
+/// through the pointer that was registered for it.
+///
+/// # Returns
+///
+/// `true` if such an allocation was registered and has been freed, or
+/// `false` if there was none.
+///
+/// # Safety
+///
+/// The allocation must no longer be used.
+pub(crate) unsafe fn free(ptr: *const u8, len: usize) -> bool {
+    let allocation = {
+        let mut leaked = leaked();
+        let Some(index) = leaked.iter().rposition(|allocation| {
+            allocation.ptr.as_ptr().cast_const() == ptr && allocation.layout.size() == len
+        }) else {
+            return false;
+        };
+        leaked.swap_remove(index)
+    };
+
+    // SAFETY: the allocation came from the global allocator with this
+    // layout, the registered pointer carries the permission to free it,
+    // and the caller guarantees that it is no longer used.
+    unsafe { alloc::alloc::dealloc(allocation.ptr.as_ptr(), allocation.layout) };
+    true
+}
+
+/// Frees every allocation this crate has leaked so far.
+///
+/// With the `cleanup` feature enabled, every leaking function records its
+/// allocations in a global registry. Calling this function, typically right
+/// before the process exits, frees all of them, so that tools such as
+/// Valgrind report a clean shutdown. Allocations leaked afterwards are
+/// recorded again and can be freed by another call.
+///
+/// # Safety
+///
+/// Every `&'static` reference returned by this crate before the call
+/// dangles afterwards. The caller must guarantee that none of them, and
+/// nothing derived from them, is used again by any thread. This includes
+/// references held inside an [`Interner`](crate::Interner), the global
+/// interner, or any other long-lived data structure.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{free_all_leaked, vec_string_to_static_str};
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = vec_string_to_static_str(&strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// drop(static_strs);
+///
+/// // SAFETY: no reference returned by this crate is used afterwards.
+/// unsafe { free_all_leaked() };
+/// ```
+pub unsafe fn free_all_leaked() {
+    let leaked = core::mem::take(&mut *leaked());
+
+    for allocation in leaked {
+        // SAFETY: every registered allocation came from the global
+        // allocator with this layout, has not been freed since, and the
+        // caller guarantees that it is no longer used.
+        unsafe { alloc::alloc::dealloc(allocation.ptr.as_ptr(), allocation.layout) };
+    }
+}
+
+/// Registers [`free_all_leaked`] to run when the process exits normally.
+///
+/// The registration uses `atexit` from the C library, so the memory is
+/// freed after `main` returns or `std::process::exit` is called.
+///
+/// # Returns
+///
+/// `true` if the handler was registered, or `false` if the C library
+/// refused to register it.
+///
+/// # Safety
+///
+/// The caller must guarantee that no reference returned by this crate is
+/// used once the process starts exiting, for example by other `atexit`
+/// handlers or by threads that are still running.
+#[cfg(unix)]
+pub unsafe fn free_all_leaked_at_exit() -> bool {
+    extern "C" fn free_at_exit() {
+        // SAFETY: the caller of `free_all_leaked_at_exit` guarantees that no
+        // reference is used while the process exits.
+        unsafe { free_all_leaked() };
+    }
+
+    // SAFETY: `free_at_exit` is a valid `extern "C"` function that does not
+    // unwind.
+    unsafe { libc::atexit(free_at_exit) == 0 }
+}
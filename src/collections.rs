@@ -0,0 +1,591 @@
+//! src/collections.rs
+
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::{str_to_static_str, vec_string_to_static_str};
+
+/// Converts a `HashMap<String, String>` into a `HashMap<&'static str, &'static str>`.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// string slices from copies of every key and value. Use with caution.
+///
+/// # Arguments
+///
+/// * `map` - The map whose keys and values are to be converted.
+///
+/// # Returns
+///
+/// A map with the same entries, keyed and valued by `&'static str`.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use vec_string_to_static_str::hashmap_string_to_static_str;
+///
+/// let map = HashMap::from([(String::from("key"), String::from("value"))]);
+/// let static_map = hashmap_string_to_static_str(&map);
+/// assert_eq!(static_map.get("key"), Some(&"value"));
+/// ```
+#[cfg(feature = "std")]
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn hashmap_string_to_static_str(
+    map: &HashMap<String, String>,
+) -> HashMap<&'static str, &'static str> {
+    let mut static_map: HashMap<&'static str, &'static str> = HashMap::with_capacity(map.len());
+
+    for (key, value) in map {
+        static_map.insert(str_to_static_str(key), str_to_static_str(value));
+    }
+
+    static_map
+}
+
+/// Converts a `HashSet<String>` into a `HashSet<&'static str>`.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// string slices from copies of every element. Use with caution.
+///
+/// # Arguments
+///
+/// * `set` - The set whose elements are to be converted.
+///
+/// # Returns
+///
+/// A set with the same elements as `&'static str`.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use vec_string_to_static_str::hashset_string_to_static_str;
+///
+/// let set = HashSet::from([String::from("hello"), String::from("world")]);
+/// let static_set = hashset_string_to_static_str(&set);
+/// assert!(static_set.contains("hello"));
+/// ```
+#[cfg(feature = "std")]
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn hashset_string_to_static_str(set: &HashSet<String>) -> HashSet<&'static str> {
+    let mut static_set: HashSet<&'static str> = HashSet::with_capacity(set.len());
+
+    for string in set {
+        static_set.insert(str_to_static_str(string));
+    }
+
+    static_set
+}
+
+/// Converts a `BTreeMap<String, String>` into a `BTreeMap<&'static str, &'static str>`.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// string slices from copies of every key and value. Use with caution.
+/// Since `&str` orders by content, the result iterates in the same order
+/// as the source map.
+///
+/// # Arguments
+///
+/// * `map` - The map whose keys and values are to be converted.
+///
+/// # Returns
+///
+/// A map with the same entries, keyed and valued by `&'static str`.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use vec_string_to_static_str::btreemap_string_to_static_str;
+///
+/// let map = BTreeMap::from([(String::from("key"), String::from("value"))]);
+/// let static_map = btreemap_string_to_static_str(&map);
+/// assert_eq!(static_map.get("key"), Some(&"value"));
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn btreemap_string_to_static_str(
+    map: &BTreeMap<String, String>,
+) -> BTreeMap<&'static str, &'static str> {
+    let mut static_map: BTreeMap<&'static str, &'static str> = BTreeMap::new();
+
+    for (key, value) in map {
+        static_map.insert(str_to_static_str(key), str_to_static_str(value));
+    }
+
+    static_map
+}
+
+/// Converts grouped `String`s into grouped `&'static str`, preserving the
+/// two-level shape of the input.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// string slices from copies of every inner string. Use with caution.
+///
+/// # Arguments
+///
+/// * `groups` - A slice of `String` vectors to be converted.
+///
+/// # Returns
+///
+/// A vector with one `Vec<&'static str>` per input group, in the same order
+/// and with the same lengths.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::nested_vec_string_to_static_str;
+///
+/// let groups = vec![vec![String::from("a")], vec![], vec![String::from("b")]];
+/// let static_groups = nested_vec_string_to_static_str(&groups);
+/// assert_eq!(static_groups, vec![vec!["a"], vec![], vec!["b"]]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn nested_vec_string_to_static_str(groups: &[Vec<String>]) -> Vec<Vec<&'static str>> {
+    let mut static_groups: Vec<Vec<&'static str>> = Vec::with_capacity(groups.len());
+
+    for group in groups {
+        static_groups.push(vec_string_to_static_str(group));
+    }
+
+    static_groups
+}
+
+/// Converts a vector of optional `String`s into a vector of optional
+/// `&'static str`, preserving the position of every `None`.
+///
+/// Only the `Some` values are copied and leaked with `Box::leak`; `None`
+/// slots leak nothing.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of optional `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of the same length where each `Some` holds a `&'static str`
+/// copy of the input and each `None` stays `None`.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_option_string_to_static_str;
+///
+/// let strings = vec![Some(String::from("a")), None, Some(String::from("b"))];
+/// let static_strs = vec_option_string_to_static_str(&strings);
+/// assert_eq!(static_strs, vec![Some("a"), None, Some("b")]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_option_string_to_static_str(strings: &[Option<String>]) -> Vec<Option<&'static str>> {
+    let mut strs: Vec<Option<&'static str>> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(match string {
+            Some(string) => Some(str_to_static_str(string)),
+            None => None,
+        });
+    }
+
+    strs
+}
+
+/// Converts a `VecDeque<String>` into a vector of `&'static str` in
+/// front-to-back order.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// string slices from copies of every element. Use with caution.
+///
+/// # Arguments
+///
+/// * `deque` - The deque whose elements are to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references in the deque's logical order.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use vec_string_to_static_str::vecdeque_string_to_static_str;
+///
+/// let mut deque = VecDeque::from([String::from("b")]);
+/// deque.push_front(String::from("a"));
+/// let static_strs = vecdeque_string_to_static_str(&deque);
+/// assert_eq!(static_strs, vec!["a", "b"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vecdeque_string_to_static_str(deque: &VecDeque<String>) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(deque.len());
+
+    for string in deque.iter() {
+        strs.push(str_to_static_str(string));
+    }
+
+    strs
+}
+
+/// Converts a vector of `(String, String)` pairs into a vector of
+/// `(&'static str, &'static str)` pairs.
+///
+/// Unlike `hashmap_string_to_static_str`, the pairs keep their order and
+/// repeated keys are preserved, each with its own value. Both elements of
+/// every pair are copied and leaked with `Box::leak`.
+///
+/// # Arguments
+///
+/// * `pairs` - A slice of key and value pairs to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` pairs in the same order as the input.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_pairs_to_static_str;
+///
+/// let pairs = vec![
+///     (String::from("accept"), String::from("text/html")),
+///     (String::from("accept"), String::from("application/json")),
+/// ];
+/// let static_pairs = vec_pairs_to_static_str(&pairs);
+/// assert_eq!(
+///     static_pairs,
+///     vec![("accept", "text/html"), ("accept", "application/json")]
+/// );
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_pairs_to_static_str(pairs: &[(String, String)]) -> Vec<(&'static str, &'static str)> {
+    let mut static_pairs: Vec<(&'static str, &'static str)> = Vec::with_capacity(pairs.len());
+
+    for (key, value) in pairs {
+        static_pairs.push((str_to_static_str(key), str_to_static_str(value)));
+    }
+
+    static_pairs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, each
+/// paired with its position in the input.
+///
+/// This is a shorthand for converting and then calling `enumerate`, for
+/// building tables that need to map entries back to their original index.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `(index, &'static str)` pairs, with indices counting up from
+/// 0 in input order.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_enumerated;
+///
+/// let strings = vec![String::from("zero"), String::from("one")];
+/// let enumerated = vec_string_to_static_str_enumerated(&strings);
+/// assert_eq!(enumerated, vec![(0, "zero"), (1, "one")]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_enumerated(strings: &[String]) -> Vec<(usize, &'static str)> {
+    let mut enumerated: Vec<(usize, &'static str)> = Vec::with_capacity(strings.len());
+
+    for (index, string) in strings.iter().enumerate() {
+        enumerated.push((index, str_to_static_str(string)));
+    }
+
+    enumerated
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, along
+/// with the byte length of each string.
+///
+/// The lengths sit in a sidecar vector parallel to the strings, which
+/// suits fixed-layout serialization that writes every length up front.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the input strings,
+/// and a vector whose `i`-th element is the length in bytes of the `i`-th
+/// string.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_with_lengths;
+///
+/// let strings = vec![String::from("hello"), String::from("wörld")];
+/// let (strs, lengths) = vec_string_to_static_str_with_lengths(&strings);
+/// assert_eq!(strs, vec!["hello", "wörld"]);
+/// assert_eq!(lengths, vec![5, 6]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_with_lengths(
+    strings: &[String],
+) -> (Vec<&'static str>, Vec<usize>) {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+    let mut lengths: Vec<usize> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(str_to_static_str(string));
+        lengths.push(string.len());
+    }
+
+    (strs, lengths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hashmap_string_to_static_str_preserves_entries() {
+        let map = HashMap::from([
+            ("key_a".to_string(), "value_a".to_string()),
+            ("key_b".to_string(), "value_b".to_string()),
+            ("你好".to_string(), "".to_string()),
+        ]);
+
+        let actual = hashmap_string_to_static_str(&map);
+
+        assert_eq!(map.len(), actual.len());
+        for (key, value) in &map {
+            assert_eq!(Some(&value.as_str()), actual.get(key.as_str()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hashmap_string_to_static_str_empty_map() {
+        let map: HashMap<String, String> = HashMap::new();
+
+        let actual = hashmap_string_to_static_str(&map);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hashset_string_to_static_str_preserves_membership() {
+        let set = HashSet::from([
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "".to_string(),
+        ]);
+
+        let actual = hashset_string_to_static_str(&set);
+
+        assert_eq!(set.len(), actual.len());
+        for string in &set {
+            assert!(actual.contains(string.as_str()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hashset_string_to_static_str_empty_set() {
+        let set: HashSet<String> = HashSet::new();
+
+        let actual = hashset_string_to_static_str(&set);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn btreemap_string_to_static_str_preserves_order() {
+        let mut map = BTreeMap::new();
+        map.insert("key_c".to_string(), "value_c".to_string());
+        map.insert("key_a".to_string(), "value_a".to_string());
+        map.insert("key_b".to_string(), "value_b".to_string());
+
+        let actual = btreemap_string_to_static_str(&map);
+
+        assert_eq!(
+            vec![
+                ("key_a", "value_a"),
+                ("key_b", "value_b"),
+                ("key_c", "value_c")
+            ],
+            actual.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn btreemap_string_to_static_str_empty_map() {
+        let map: BTreeMap<String, String> = BTreeMap::new();
+
+        let actual = btreemap_string_to_static_str(&map);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn nested_vec_string_to_static_str_ragged_groups() {
+        let groups = vec![
+            vec![],
+            vec!["string_a".to_string()],
+            vec!["string_b".to_string(), "".to_string(), "你好".to_string()],
+        ];
+
+        let actual = nested_vec_string_to_static_str(&groups);
+
+        assert_eq!(3, actual.len());
+        assert_eq!(
+            vec![0, 1, 3],
+            actual.iter().map(Vec::len).collect::<Vec<_>>()
+        );
+        assert_eq!(groups, actual);
+    }
+
+    #[test]
+    fn nested_vec_string_to_static_str_empty_vector() {
+        let groups: Vec<Vec<String>> = Vec::new();
+
+        let actual = nested_vec_string_to_static_str(&groups);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn vec_option_string_to_static_str_preserves_nones() {
+        let strings = vec![Some("a".to_string()), None, Some("b".to_string())];
+
+        let actual = vec_option_string_to_static_str(&strings);
+
+        assert_eq!(vec![Some("a"), None, Some("b")], actual);
+    }
+
+    #[test]
+    fn vec_option_string_to_static_str_leaks_nothing_for_nones() {
+        let strings: Vec<Option<String>> = vec![None, None, None];
+
+        let (actual, allocations) = count_allocations(|| vec_option_string_to_static_str(&strings));
+
+        assert_eq!(vec![None, None, None], actual);
+        // Only the output vector allocates.
+        assert_eq!(1, allocations);
+    }
+
+    #[test]
+    fn vecdeque_string_to_static_str_preserves_order() {
+        let mut deque = VecDeque::new();
+        deque.push_back("string_c".to_string());
+        deque.push_front("string_b".to_string());
+        deque.push_back("string_d".to_string());
+        deque.push_front("string_a".to_string());
+
+        let actual = vecdeque_string_to_static_str(&deque);
+
+        assert_eq!(vec!["string_a", "string_b", "string_c", "string_d"], actual);
+    }
+
+    #[test]
+    fn vecdeque_string_to_static_str_empty_deque() {
+        let deque: VecDeque<String> = VecDeque::new();
+
+        let actual = vecdeque_string_to_static_str(&deque);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn vec_pairs_to_static_str_preserves_repeated_keys() {
+        let pairs = vec![
+            ("key_a".to_string(), "value_1".to_string()),
+            ("key_b".to_string(), "value_2".to_string()),
+            ("key_a".to_string(), "value_3".to_string()),
+        ];
+
+        let actual = vec_pairs_to_static_str(&pairs);
+
+        assert_eq!(
+            vec![
+                ("key_a", "value_1"),
+                ("key_b", "value_2"),
+                ("key_a", "value_3"),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn vec_pairs_to_static_str_empty_vector() {
+        let pairs: Vec<(String, String)> = Vec::new();
+
+        let actual = vec_pairs_to_static_str(&pairs);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn vec_string_to_static_str_enumerated_counts_from_zero() {
+        let strings: Vec<String> = (0..5).map(|i| format!("string_{i}")).collect();
+
+        let actual = vec_string_to_static_str_enumerated(&strings);
+
+        assert_eq!(strings.len(), actual.len());
+        for (expected_index, &(index, s)) in actual.iter().enumerate() {
+            assert_eq!(expected_index, index);
+            assert_eq!(strings[index], s);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_enumerated_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = vec_string_to_static_str_enumerated(&strings);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn vec_string_to_static_str_with_lengths_matches_output() {
+        let strings = vec![
+            "string_a".to_string(),
+            String::new(),
+            "héllo".to_string(),
+            "日本語".to_string(),
+            "🦀".to_string(),
+        ];
+
+        let (strs, lengths) = vec_string_to_static_str_with_lengths(&strings);
+
+        assert_eq!(strings, strs);
+        assert_eq!(vec![8, 0, 6, 9, 4], lengths);
+        for (s, length) in strs.iter().zip(&lengths) {
+            assert_eq!(s.len(), *length);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_with_lengths_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let (strs, lengths) = vec_string_to_static_str_with_lengths(&strings);
+
+        assert!(strs.is_empty());
+        assert!(lengths.is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+//! src/cow.rs
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use crate::str_to_static_str;
+
+/// Converts a vector of `Cow<'static, str>` into a vector of `&'static str`,
+/// leaking only the owned entries.
+///
+/// `Cow::Borrowed` entries already hold a `&'static str` and are returned
+/// as is. Only `Cow::Owned` entries are copied and leaked with `Box::leak`.
+///
+/// # Arguments
+///
+/// * `cows` - A slice of `Cow<'static, str>` values to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references, borrowed entries pointing at the
+/// original data and owned entries pointing at leaked copies.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+/// use vec_string_to_static_str::cow_to_static_str;
+///
+/// let cows = vec![Cow::Borrowed("hello"), Cow::Owned(String::from("world"))];
+/// let static_strs = cow_to_static_str(&cows);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn cow_to_static_str(cows: &[Cow<'static, str>]) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(cows.len());
+
+    for cow in cows {
+        strs.push(match cow {
+            Cow::Borrowed(s) => s,
+            Cow::Owned(s) => str_to_static_str(s),
+        });
+    }
+
+    strs
+}
+
+/// Converts a vector of `Cow<str>` with any borrow lifetime into a vector
+/// of `&'static str`.
+///
+/// Unlike [`cow_to_static_str`], borrowed entries cannot be reused here,
+/// since they only live as long as their source. Every entry, whether
+/// `Cow::Borrowed` or `Cow::Owned`, is therefore copied and leaked with
+/// `Box::leak`.
+///
+/// # Arguments
+///
+/// * `cows` - A slice of `Cow<str>` values to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to leaked copies of every entry.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+/// use vec_string_to_static_str::cow_slice_to_static_str;
+///
+/// let source = String::from("hello");
+/// let cows = vec![Cow::Borrowed(source.as_str()), Cow::Owned(String::from("world"))];
+/// let static_strs = cow_slice_to_static_str(&cows);
+/// drop(cows);
+/// drop(source);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn cow_slice_to_static_str(cows: &[Cow<str>]) -> Vec<&'static str> {
+    crate::to_static_strs(cows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cow_to_static_str_mixed_variants() {
+        let cows = vec![Cow::Borrowed("lit"), Cow::Owned(String::from("dyn"))];
+
+        let actual = cow_to_static_str(&cows);
+
+        assert_eq!(vec!["lit", "dyn"], actual);
+    }
+
+    #[test]
+    fn cow_to_static_str_reuses_borrowed() {
+        let literal: &'static str = "lit";
+        let cows = vec![Cow::Borrowed(literal)];
+
+        let actual = cow_to_static_str(&cows);
+
+        assert!(std::ptr::eq(literal, actual[0]));
+    }
+
+    #[test]
+    fn cow_to_static_str_copies_owned() {
+        let cows: Vec<Cow<'static, str>> = vec![Cow::Owned(String::from("dyn"))];
+
+        let actual = cow_to_static_str(&cows);
+
+        assert!(!std::ptr::eq(cows[0].as_ref(), actual[0]));
+    }
+
+    #[test]
+    fn cow_slice_to_static_str_non_static_borrows() {
+        let source = String::from("borrowed owned");
+        let cows: Vec<Cow<str>> = vec![
+            Cow::Borrowed(&source[..8]),
+            Cow::Owned(String::from("owned")),
+            Cow::Borrowed(""),
+        ];
+
+        let actual = cow_slice_to_static_str(&cows);
+        let borrowed_ptr = source.as_ptr();
+        drop(cows);
+        drop(source);
+
+        assert_eq!(vec!["borrowed", "owned", ""], actual);
+        assert_ne!(borrowed_ptr, actual[0].as_ptr());
+    }
+
+    #[test]
+    fn cow_slice_to_static_str_copies_owned_entries() {
+        let cows: Vec<Cow<str>> = vec![Cow::Owned(String::from("owned"))];
+
+        let actual = cow_slice_to_static_str(&cows);
+
+        assert_eq!(vec!["owned"], actual);
+        assert_ne!(cows[0].as_ptr(), actual[0].as_ptr());
+    }
+}
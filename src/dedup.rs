@@ -0,0 +1,368 @@
+//! src/dedup.rs
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::str_to_static_str;
+
+/// Converts a vector of `String`s into a vector of `&'static str` without
+/// duplicates, keeping the first occurrence of each value.
+///
+/// Unlike [`leak_sorted_dedup`](crate::leak_sorted_dedup), the output keeps
+/// the original order: later duplicates are dropped entirely, and every
+/// distinct string is leaked exactly once.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the distinct input
+/// strings, in order of first occurrence.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_dedup_preserve_order;
+///
+/// let strings = vec![
+///     String::from("world"),
+///     String::from("hello"),
+///     String::from("world"),
+/// ];
+/// let static_strs = leak_dedup_preserve_order(&strings);
+/// assert_eq!(static_strs, vec!["world", "hello"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_dedup_preserve_order(strings: &[String]) -> Vec<&'static str> {
+    let mut seen: BTreeSet<&str> = BTreeSet::new();
+    let mut strs: Vec<&'static str> = Vec::new();
+
+    for string in strings {
+        if seen.insert(string) {
+            strs.push(str_to_static_str(string));
+        }
+    }
+
+    strs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, reusing
+/// the strings of an already-leaked table wherever possible.
+///
+/// This supports growing a leaked table incrementally: every string equal
+/// to an entry of `existing` resolves to that entry's `&'static str`
+/// instead of being leaked again. Only strings missing from `existing`
+/// are leaked, each of them once, even if it repeats within `new`.
+///
+/// # Arguments
+///
+/// * `existing` - The already-leaked strings to reuse.
+/// * `new` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` with one entry per string of `new`, in input
+/// order. Entries equal to a string of `existing` are pointer-identical to
+/// it.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::extend_static_table;
+///
+/// let table: &[&'static str] = &["hello", "world"];
+/// let new = vec![String::from("world"), String::from("again")];
+/// let extended = extend_static_table(table, &new);
+/// assert_eq!(extended, vec!["world", "again"]);
+/// assert!(std::ptr::eq(extended[0], table[1]));
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn extend_static_table(existing: &[&'static str], new: &[String]) -> Vec<&'static str> {
+    let mut known: BTreeSet<&'static str> = BTreeSet::new();
+
+    for &s in existing {
+        known.insert(s);
+    }
+
+    let mut strs: Vec<&'static str> = Vec::with_capacity(new.len());
+
+    for string in new {
+        match known.get(string.as_str()) {
+            Some(&s) => strs.push(s),
+            None => {
+                let leaked = str_to_static_str(string);
+                known.insert(leaked);
+                strs.push(leaked);
+            }
+        }
+    }
+
+    strs
+}
+
+/// Builds a string-to-id table, leaking each distinct string once and
+/// assigning it a dense integer id.
+///
+/// Ids are assigned in order of first occurrence, so the id of a string is
+/// its index in the returned vector. Repeated inputs map to the same id.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the distinct input
+/// strings, in order of first occurrence, and a map from each of them to
+/// its index in that vector.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::build_static_str_index;
+///
+/// let strings = vec![String::from("b"), String::from("a"), String::from("b")];
+/// let (strs, ids) = build_static_str_index(&strings);
+/// assert_eq!(strs, vec!["b", "a"]);
+/// assert_eq!(ids["a"], 1);
+/// assert_eq!(strs[ids["b"]], "b");
+/// ```
+#[cfg(feature = "std")]
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn build_static_str_index(
+    strings: &[String],
+) -> (Vec<&'static str>, HashMap<&'static str, usize>) {
+    let mut ids: HashMap<&'static str, usize> = HashMap::new();
+    let mut strs: Vec<&'static str> = Vec::new();
+
+    for string in strings {
+        if !ids.contains_key(string.as_str()) {
+            let leaked = str_to_static_str(string);
+            ids.insert(leaked, strs.len());
+            strs.push(leaked);
+        }
+    }
+
+    (strs, ids)
+}
+
+/// Builds a leaked lookup set, leaking each distinct string once.
+///
+/// This is a runtime stand-in for a compile-time perfect hash set: the set
+/// itself is an ordinary `HashSet`, but its elements are `&'static str`, so
+/// it can be stored in a `static` cell and queried with [`is_member`] in
+/// O(1). Repeated inputs are leaked only the first time they are seen.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A set holding a `&'static str` copy of each distinct input string.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{build_static_str_set, is_member};
+///
+/// let strings = vec![String::from("GET"), String::from("POST")];
+/// let methods = build_static_str_set(&strings);
+/// assert!(is_member(&methods, "GET"));
+/// assert!(!is_member(&methods, "PATCH"));
+/// ```
+#[cfg(feature = "std")]
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn build_static_str_set(strings: &[String]) -> HashSet<&'static str> {
+    let mut set: HashSet<&'static str> = HashSet::with_capacity(strings.len());
+
+    for string in strings {
+        if !set.contains(string.as_str()) {
+            set.insert(str_to_static_str(string));
+        }
+    }
+
+    set
+}
+
+/// Returns whether `s` is one of the strings in a set built by
+/// [`build_static_str_set`].
+///
+/// # Arguments
+///
+/// * `set` - The set to search.
+/// * `s` - The string to look for.
+///
+/// # Returns
+///
+/// `true` if the set contains a string equal to `s`.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{build_static_str_set, is_member};
+///
+/// let set = build_static_str_set(&[String::from("hello")]);
+/// assert!(is_member(&set, "hello"));
+/// ```
+#[cfg(feature = "std")]
+pub fn is_member(set: &HashSet<&'static str>, s: &str) -> bool {
+    set.contains(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+    use crate::vec_string_to_static_str;
+
+    #[test]
+    fn leak_dedup_preserve_order_keeps_first_occurrence() {
+        let strings = vec![
+            "b".to_string(),
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "a".to_string(),
+        ];
+
+        let actual = leak_dedup_preserve_order(&strings);
+
+        assert_eq!(vec!["b", "a", "c"], actual);
+    }
+
+    #[test]
+    fn leak_dedup_preserve_order_no_duplicates() {
+        let strings = vec!["string_b".to_string(), "string_a".to_string()];
+
+        let actual = leak_dedup_preserve_order(&strings);
+
+        assert_eq!(vec!["string_b", "string_a"], actual);
+    }
+
+    #[test]
+    fn leak_dedup_preserve_order_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = leak_dedup_preserve_order(&strings);
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+
+    #[test]
+    fn extend_static_table_reuses_existing_pointers() {
+        let existing = vec_string_to_static_str(&["string_a".to_string(), "string_b".to_string()]);
+        let new = vec![
+            "string_b".to_string(),
+            "string_c".to_string(),
+            "string_a".to_string(),
+            "string_d".to_string(),
+        ];
+
+        let actual = extend_static_table(&existing, &new);
+
+        assert_eq!(vec!["string_b", "string_c", "string_a", "string_d"], actual);
+        assert!(core::ptr::eq(existing[1], actual[0]));
+        assert!(core::ptr::eq(existing[0], actual[2]));
+        assert!(!core::ptr::eq(new[1].as_str(), actual[1]));
+    }
+
+    #[test]
+    fn extend_static_table_leaks_only_missing() {
+        let existing = vec_string_to_static_str(&["string_a".to_string()]);
+        let new = vec![
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_b".to_string(),
+        ];
+
+        let (actual, allocations) = count_allocations(|| extend_static_table(&existing, &new));
+
+        assert!(core::ptr::eq(actual[1], actual[2]));
+        // The set's node, the output vector and the single leaked string.
+        assert_eq!(3, allocations);
+    }
+
+    #[test]
+    fn extend_static_table_empty_existing() {
+        let new = vec!["string_a".to_string(), "string_a".to_string()];
+
+        let actual = extend_static_table(&[], &new);
+
+        assert_eq!(vec!["string_a", "string_a"], actual);
+        assert!(core::ptr::eq(actual[0], actual[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn build_static_str_index_assigns_dense_ids() {
+        let strings = vec![
+            "b".to_string(),
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "a".to_string(),
+        ];
+
+        let (strs, ids) = build_static_str_index(&strings);
+
+        assert_eq!(vec!["b", "a", "c"], strs);
+        assert_eq!(strs.len(), ids.len());
+        for (index, s) in strs.iter().enumerate() {
+            assert_eq!(Some(&index), ids.get(s));
+        }
+        for string in &strings {
+            assert_eq!(string, strs[ids[string.as_str()]]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn build_static_str_index_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let (strs, ids) = build_static_str_index(&strings);
+
+        assert!(strs.is_empty());
+        assert!(ids.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn build_static_str_set_membership() {
+        let strings = vec![
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_a".to_string(),
+        ];
+
+        let actual = build_static_str_set(&strings);
+
+        assert_eq!(2, actual.len());
+        assert!(is_member(&actual, "string_a"));
+        assert!(is_member(&actual, "string_b"));
+        assert!(!is_member(&actual, "string_c"));
+        assert!(!is_member(&actual, ""));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn build_static_str_set_leaks_only_unique() {
+        let strings = vec!["string_a".to_string(); 100];
+
+        let (actual, allocations) = count_allocations(|| build_static_str_set(&strings));
+
+        assert_eq!(1, actual.len());
+        // The set's table and the single leaked string.
+        assert_eq!(2, allocations);
+    }
+}
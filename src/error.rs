@@ -0,0 +1,82 @@
+//! src/error.rs
+
+use core::error::Error;
+use core::fmt;
+
+/// The error returned when a conversion would leak more bytes than allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeakLimitError {
+    /// The number of bytes the conversion would have leaked.
+    pub requested: usize,
+    /// The maximum number of bytes the caller allowed.
+    pub limit: usize,
+}
+
+impl fmt::Display for LeakLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "leaking {} bytes would exceed the limit of {} bytes",
+            self.requested, self.limit
+        )
+    }
+}
+
+impl Error for LeakLimitError {}
+
+/// The error returned when interning a new string would exceed the budget
+/// of an `Interner` or a `CompactingInterner`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetExceeded {
+    /// The number of bytes the new string would have leaked.
+    pub requested: usize,
+    /// The number of bytes the interner has already leaked.
+    pub used: usize,
+    /// The maximum number of bytes the interner may leak.
+    pub budget: usize,
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "interning {} more bytes would exceed the budget of {} bytes ({} bytes used)",
+            self.requested, self.budget, self.used
+        )
+    }
+}
+
+impl Error for BudgetExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leak_limit_error_display() {
+        let error = LeakLimitError {
+            requested: 10,
+            limit: 4,
+        };
+
+        let actual = error.to_string();
+
+        assert_eq!("leaking 10 bytes would exceed the limit of 4 bytes", actual);
+    }
+
+    #[test]
+    fn budget_exceeded_display() {
+        let error = BudgetExceeded {
+            requested: 8,
+            used: 12,
+            budget: 16,
+        };
+
+        let actual = error.to_string();
+
+        assert_eq!(
+            "interning 8 more bytes would exceed the budget of 16 bytes (12 bytes used)",
+            actual
+        );
+    }
+}
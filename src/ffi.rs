@@ -0,0 +1,194 @@
+//! src/ffi.rs
+
+use alloc::ffi::{CString, NulError};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+
+use crate::{leak_boxed, vec_string_to_static_str};
+
+/// Converts a vector of `String`s into a vector of `&'static CStr`.
+///
+/// Each string is copied into a `CString`, which appends the terminating
+/// NUL byte, and leaked with `Box::leak`. Every string is validated before
+/// anything is leaked, so if one of them contains an interior NUL byte the
+/// error is returned and no memory is leaked at all.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static CStr` references to NUL-terminated copies of the
+/// input strings, or the `NulError` of the first string containing an
+/// interior NUL byte.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_cstr;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_cstrs = vec_string_to_static_cstr(&strings).unwrap();
+/// assert_eq!(static_cstrs, vec![c"hello", c"world"]);
+///
+/// let invalid = vec![String::from("hel\0lo")];
+/// assert!(vec_string_to_static_cstr(&invalid).is_err());
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_cstr(strings: &[String]) -> Result<Vec<&'static CStr>, NulError> {
+    let mut cstrings: Vec<CString> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        cstrings.push(CString::new(string.as_str())?);
+    }
+
+    let mut cstrs: Vec<&'static CStr> = Vec::with_capacity(cstrings.len());
+
+    for cstring in cstrings {
+        let bytes = cstring.as_bytes_with_nul().len();
+        cstrs.push(leak_boxed(cstring.into_boxed_c_str(), bytes));
+    }
+
+    Ok(cstrs)
+}
+
+/// Converts a vector of `String`s into leaked pointer and length pairs.
+///
+/// Each string is copied and leaked with `Box::leak`, like
+/// [`vec_string_to_static_str`], and described by the address of its first
+/// byte and its length in bytes, which is the form FFI functions taking a
+/// `const uint8_t *` and a `size_t` expect. The strings are not
+/// NUL-terminated; use [`vec_string_to_static_cstr`] for that.
+///
+/// Because the memory is leaked, every pointer stays valid and never moves
+/// for the rest of the program, so the pairs can be handed to foreign code
+/// that keeps them indefinitely. Foreign code must not write through them.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `(pointer, length)` pairs describing UTF-8 copies of the
+/// input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_to_raw_parts;
+///
+/// let strings = vec![String::from("hello")];
+/// let raw_parts = leak_to_raw_parts(&strings);
+/// assert_eq!(raw_parts[0].1, 5);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_to_raw_parts(strings: &[String]) -> Vec<(*const u8, usize)> {
+    leak_to_raw_parts_with_strs(strings).1
+}
+
+/// Converts a vector of `String`s into leaked pointer and length pairs,
+/// along with a safe `&'static str` view of the same memory.
+///
+/// This behaves like [`leak_to_raw_parts`], but also returns the leaked
+/// strings themselves, so Rust code can keep reading them without going
+/// through the raw pointers.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the input strings,
+/// and a vector with the `(pointer, length)` pair of each of them.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_to_raw_parts_with_strs;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let (static_strs, raw_parts) = leak_to_raw_parts_with_strs(&strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// assert_eq!(raw_parts[1], (static_strs[1].as_ptr(), 5));
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_to_raw_parts_with_strs(
+    strings: &[String],
+) -> (Vec<&'static str>, Vec<(*const u8, usize)>) {
+    let strs = vec_string_to_static_str(strings);
+    let mut raw_parts: Vec<(*const u8, usize)> = Vec::with_capacity(strs.len());
+
+    for s in &strs {
+        raw_parts.push((s.as_ptr(), s.len()));
+    }
+
+    (strs, raw_parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_string_to_static_cstr_all_valid() {
+        let strings = vec!["string_a".to_string(), "".to_string(), "你好".to_string()];
+
+        let actual = vec_string_to_static_cstr(&strings).unwrap();
+
+        assert_eq!(strings.len(), actual.len());
+        for (string, cstr) in strings.iter().zip(&actual) {
+            assert_eq!(string.as_bytes(), cstr.to_bytes());
+            assert_eq!(Some(&0), cstr.to_bytes_with_nul().last());
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_cstr_interior_nul() {
+        let strings = vec![
+            "string_a".to_string(),
+            "string\0b".to_string(),
+            "string_c".to_string(),
+        ];
+
+        let actual = vec_string_to_static_cstr(&strings);
+
+        let error = actual.unwrap_err();
+        assert_eq!(6, error.nul_position());
+        assert_eq!(b"string\0b".to_vec(), error.into_vec());
+    }
+
+    #[test]
+    fn leak_to_raw_parts_reconstructs_strings() {
+        let strings = vec!["string_a".to_string(), "".to_string(), "你好".to_string()];
+
+        let actual = leak_to_raw_parts(&strings);
+
+        assert_eq!(strings.len(), actual.len());
+        for (string, &(ptr, len)) in strings.iter().zip(&actual) {
+            // SAFETY: `ptr` points to `len` bytes of leaked memory that is
+            // never freed or mutated.
+            let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+            assert_eq!(Ok(string.as_str()), core::str::from_utf8(bytes));
+        }
+    }
+
+    #[test]
+    fn leak_to_raw_parts_with_strs_match_views() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let (actual, raw_parts) = leak_to_raw_parts_with_strs(&strings);
+
+        assert_eq!(strings, actual);
+        for (s, &(ptr, len)) in actual.iter().zip(&raw_parts) {
+            assert_eq!(s.as_ptr(), ptr);
+            assert_eq!(s.len(), len);
+        }
+    }
+}
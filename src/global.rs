@@ -0,0 +1,109 @@
+//! src/global.rs
+
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use crate::{InternStats, Interner};
+
+static GLOBAL_INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+
+fn global_interner() -> &'static Mutex<Interner> {
+    GLOBAL_INTERNER.get_or_init(|| Mutex::new(Interner::new()))
+}
+
+/// Interns a string slice in a single process-wide interner.
+///
+/// Equal strings return the same `&'static str`, regardless of which thread
+/// interned them first. Every distinct string is leaked and never freed, so
+/// this is intended for program-lifetime data such as configuration keys.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be interned.
+///
+/// # Returns
+///
+/// A `&'static str` that is pointer-identical for every call with equal
+/// content, across all threads.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::global_intern;
+///
+/// let first = global_intern("config.key");
+/// let second = global_intern(&String::from("config.key"));
+/// assert!(std::ptr::eq(first, second));
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn global_intern(s: &str) -> &'static str {
+    global_interner()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .intern(s)
+        .unwrap_or_else(|_| unreachable!("the global interner has no budget"))
+}
+
+/// Returns a summary of everything interned by [`global_intern`] so far.
+///
+/// This takes the same lock as [`global_intern`], so it is safe to call
+/// from any thread at any time, for example to report the footprint of the
+/// interner from a health check endpoint.
+///
+/// # Returns
+///
+/// The [`InternStats`] of the global interner, where `unique` and `bytes`
+/// describe the strings it has leaked.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{global_intern, global_intern_stats};
+///
+/// let _ = global_intern("config.key");
+/// let stats = global_intern_stats();
+/// assert!(stats.unique >= 1);
+/// assert!(stats.bytes >= "config.key".len());
+/// ```
+pub fn global_intern_stats() -> InternStats {
+    global_interner()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .stats()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn global_intern_returns_content() {
+        let actual = global_intern("global_string_a");
+
+        assert_eq!("global_string_a", actual);
+    }
+
+    #[test]
+    fn global_intern_same_word_across_calls() {
+        let first = global_intern("global_string_b");
+        let second = global_intern(&String::from("global_string_b"));
+
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn global_intern_same_word_across_threads() {
+        let handles: Vec<_> = (0..8)
+            .map(|_| thread::spawn(|| global_intern("shared")))
+            .collect();
+
+        let actual: Vec<&'static str> = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        let expected = global_intern("shared");
+        assert!(actual.iter().all(|&s| std::ptr::eq(expected, s)));
+    }
+}
@@ -0,0 +1,130 @@
+//! src/guard.rs
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Owns leaked string slices and reclaims them when dropped.
+///
+/// A `LeakGuard` leaks copies of its input strings just like
+/// [`vec_string_to_static_str`](crate::vec_string_to_static_str), but keeps
+/// track of every allocation it made and frees them all in its `Drop` impl.
+/// This makes per-request conversions usable in long-running processes
+/// without growing memory forever.
+///
+/// # Soundness
+///
+/// The strings owned by a guard are only valid while the guard is alive.
+/// [`LeakGuard::as_static_strs`] therefore ties the returned references to
+/// the borrow of the guard, so the compiler rejects any use after the guard
+/// is dropped. Never extend those references to `'static` yourself (for
+/// example with `transmute`): once the guard is dropped they dangle, and
+/// using them is undefined behavior.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::LeakGuard;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let guard = LeakGuard::new(&strings);
+/// assert_eq!(guard.as_static_strs(), ["hello", "world"]);
+/// drop(guard); // The copies of "hello" and "world" are freed here.
+/// ```
+pub struct LeakGuard {
+    strs: Vec<&'static str>,
+    // Raw pointers straight from `Box::into_raw`, kept separately from the
+    // shared references so that `Drop` frees through the original pointers.
+    ptrs: Vec<*mut str>,
+}
+
+impl LeakGuard {
+    /// Copies and leaks each string, remembering the allocations so they
+    /// can be reclaimed when the guard is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `strings` - A slice of `String`s to be converted.
+    pub fn new(strings: &[String]) -> Self {
+        let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+        let mut ptrs: Vec<*mut str> = Vec::with_capacity(strings.len());
+
+        for string in strings {
+            let ptr = Box::into_raw(string.clone().into_boxed_str());
+            // SAFETY: `ptr` comes from `Box::into_raw` and is only freed in
+            // `Drop`, after which `strs` is never read again.
+            strs.push(unsafe { &*ptr });
+            ptrs.push(ptr);
+        }
+
+        Self { strs, ptrs }
+    }
+
+    /// Returns the guarded string slices.
+    ///
+    /// # Returns
+    ///
+    /// A slice of string references that are valid for as long as the
+    /// guard is borrowed.
+    pub fn as_static_strs(&self) -> &[&str] {
+        &self.strs
+    }
+}
+
+// SAFETY: a guard uniquely owns its allocations, like a `Vec<Box<str>>`,
+// and only ever reads them through shared references, so it can be moved to
+// and shared with other threads.
+unsafe impl Send for LeakGuard {}
+// SAFETY: see the `Send` impl above.
+unsafe impl Sync for LeakGuard {}
+
+impl Drop for LeakGuard {
+    fn drop(&mut self) {
+        self.strs.clear();
+
+        for ptr in self.ptrs.drain(..) {
+            // SAFETY: every pointer was produced by `Box::into_raw` in
+            // `new` and is freed exactly once, here.
+            drop(unsafe { Box::from_raw(ptr) });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_deallocations;
+
+    #[test]
+    fn leak_guard_as_static_strs() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let guard = LeakGuard::new(&strings);
+
+        assert_eq!(["string_a", "string_b"], guard.as_static_strs());
+    }
+
+    #[test]
+    fn leak_guard_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let guard = LeakGuard::new(&strings);
+
+        assert!(guard.as_static_strs().is_empty());
+    }
+
+    #[test]
+    fn leak_guard_reclaims_on_drop() {
+        let strings = vec![
+            String::from("string_a"),
+            String::from("string_b"),
+            String::from("string_c"),
+        ];
+        let guard = LeakGuard::new(&strings);
+
+        let (_, deallocations) = count_deallocations(|| drop(guard));
+
+        // One deallocation per guarded string plus the two bookkeeping vectors.
+        assert_eq!(strings.len() + 2, deallocations);
+    }
+}
@@ -0,0 +1,1352 @@
+//! src/interner.rs
+
+use core::fmt;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use hashbrown::HashTable;
+
+use crate::static_strs::Truncated;
+use crate::{str_to_static_str, BudgetExceeded};
+
+/// Summary of how much leaking an interning conversion avoided.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InternStats {
+    /// The number of input strings, saturating at `usize::MAX`.
+    pub total: usize,
+    /// The number of distinct strings, each of which was leaked once.
+    pub unique: usize,
+    /// The number of bytes leaked for the distinct strings.
+    pub bytes: usize,
+    /// The number of bytes that duplicates would otherwise have leaked,
+    /// saturating at `usize::MAX`.
+    pub bytes_saved: usize,
+}
+
+/// A stateful string interner that leaks each distinct string only once.
+///
+/// Strings can be fed into the interner incrementally over the lifetime of
+/// a program. Interning a string equal to one seen before returns the
+/// previously leaked `&'static str` instead of leaking a new copy.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::Interner;
+///
+/// let mut interner = Interner::new();
+/// let first = interner.intern("hello").unwrap();
+/// let second = interner.intern(&String::from("hello")).unwrap();
+/// assert!(std::ptr::eq(first, second));
+/// assert_eq!(interner.len(), 1);
+/// ```
+///
+/// The hasher defaults to the standard library's `RandomState`, and can be
+/// replaced with a faster one with [`Interner::with_hasher`].
+///
+/// Cloning an interner snapshots its tables: the clone returns the same
+/// `&'static str` references for strings interned before the clone, which
+/// is sound since they are leaked, while strings interned afterwards are
+/// leaked separately by each copy. A budget applies to each copy on its own.
+///
+/// Its `Debug` output shows the number of strings and bytes interned, and
+/// a sample of the strings that is truncated with `...` for large tables.
+#[derive(Clone)]
+pub struct Interner<S = RandomState> {
+    strings: HashTable<&'static str>,
+    ascii_case_insensitive: HashTable<&'static str>,
+    hash_builder: S,
+    total: usize,
+    bytes: usize,
+    bytes_saved: usize,
+    budget: Option<usize>,
+}
+
+impl Interner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Creates an empty interner with room for at least `capacity` distinct
+    /// strings.
+    ///
+    /// Pre-sizing avoids rehashing the table while bulk-loading a dataset of
+    /// known size. The capacity only affects the lookup table; nothing is
+    /// leaked up front.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of distinct strings to make room for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::with_capacity(1000);
+    /// assert!(interner.capacity() >= 1000);
+    /// let _ = interner.intern("hello");
+    /// assert_eq!(interner.len(), 1);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+
+    /// Creates an interner pre-populated with a saved dictionary.
+    ///
+    /// This restores an interner from the output of
+    /// [`Interner::to_dictionary`], for example one persisted between runs
+    /// with the `serde` feature. Every word is leaked once, and interning any
+    /// of them afterwards is a cache hit. The interner has no budget, so
+    /// loading a dictionary never fails. To load a dictionary into an
+    /// interner with a custom hasher, create it with
+    /// [`Interner::with_capacity_and_hasher`] and `extend` it with the words.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The strings to pre-populate the interner with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let words = vec![String::from("hello"), String::from("world")];
+    /// let interner = Interner::from_dictionary(&words);
+    /// assert!(interner.contains("hello"));
+    /// assert_eq!(interner.len(), 2);
+    /// ```
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn from_dictionary(words: &[String]) -> Self {
+        let mut interner = Self::with_capacity(words.len());
+        for word in words {
+            let _ = interner.intern(word);
+        }
+        interner
+    }
+
+    /// Creates an empty interner that leaks at most `max_bytes` bytes.
+    ///
+    /// Once the budget is used up, [`Interner::intern`] returns an error
+    /// for every string that has not been interned yet. Strings that are
+    /// already interned keep resolving, since returning them leaks nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_bytes` - The maximum number of string bytes the interner may
+    ///   leak over its lifetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::with_budget(8);
+    /// assert!(interner.intern("hello").is_ok());
+    /// assert!(interner.intern("world").is_err());
+    /// assert!(interner.intern("hello").is_ok());
+    /// ```
+    pub fn with_budget(max_bytes: usize) -> Self {
+        Self::with_budget_and_hasher(max_bytes, RandomState::new())
+    }
+}
+
+impl<S: BuildHasher + Default> Default for Interner<S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<S> fmt::Debug for Interner<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interner")
+            .field("len", &self.strings.len())
+            .field("bytes", &self.bytes)
+            .field("budget", &self.budget)
+            .field("strings", &Truncated(self.strings.iter()))
+            .finish()
+    }
+}
+
+impl<S: BuildHasher> Interner<S> {
+    /// Creates an empty interner that hashes strings with `hash_builder`.
+    ///
+    /// Interning works exactly as with [`Interner::new`]; only the hashing
+    /// of the lookup tables changes, so a faster or deterministic hasher can
+    /// be swapped in for a given workload.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash_builder` - The hasher used to hash interned strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    /// let first = interner.intern("hello").unwrap();
+    /// assert!(std::ptr::eq(first, interner.intern("hello").unwrap()));
+    /// ```
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            strings: HashTable::new(),
+            ascii_case_insensitive: HashTable::new(),
+            hash_builder,
+            total: 0,
+            bytes: 0,
+            bytes_saved: 0,
+            budget: None,
+        }
+    }
+
+    /// Creates an empty interner with room for at least `capacity` distinct
+    /// strings, hashing them with `hash_builder`.
+    ///
+    /// This combines [`Interner::with_capacity`] and
+    /// [`Interner::with_hasher`].
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of distinct strings to make room for.
+    /// * `hash_builder` - The hasher used to hash interned strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let mut interner = Interner::with_capacity_and_hasher(1000, hasher);
+    /// assert!(interner.capacity() >= 1000);
+    /// interner.extend(["hello", "world"]);
+    /// assert_eq!(interner.len(), 2);
+    /// ```
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut interner = Self::with_hasher(hash_builder);
+        interner.reserve(capacity);
+        interner
+    }
+
+    /// Creates an empty interner that leaks at most `max_bytes` bytes,
+    /// hashing strings with `hash_builder`.
+    ///
+    /// This combines [`Interner::with_budget`] and
+    /// [`Interner::with_hasher`].
+    ///
+    /// # Arguments
+    ///
+    /// * `max_bytes` - The maximum number of string bytes the interner may
+    ///   leak over its lifetime.
+    /// * `hash_builder` - The hasher used to hash interned strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let mut interner = Interner::with_budget_and_hasher(8, hasher);
+    /// assert!(interner.intern("hello").is_ok());
+    /// assert!(interner.intern("world").is_err());
+    /// ```
+    pub fn with_budget_and_hasher(max_bytes: usize, hash_builder: S) -> Self {
+        Self {
+            budget: Some(max_bytes),
+            ..Self::with_hasher(hash_builder)
+        }
+    }
+
+    /// Returns the hasher used to hash interned strings.
+    ///
+    /// Hashes passed to [`Interner::intern_prehashed`] must be computed
+    /// with this hasher, for example with `interner.hasher().hash_one(s)`.
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Interns a string slice, leaking it only if it has not been seen before.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to be interned.
+    ///
+    /// # Returns
+    ///
+    /// A `&'static str` that is pointer-identical for every call with equal
+    /// content, or a [`BudgetExceeded`] error if `s` is new and leaking it
+    /// would exceed the budget set with [`Interner::with_budget`]. An
+    /// interner without a budget never returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let first = interner.intern("hello").unwrap();
+    /// let second = interner.intern("hello").unwrap();
+    /// assert!(std::ptr::eq(first, second));
+    /// ```
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn intern(&mut self, s: &str) -> Result<&'static str, BudgetExceeded> {
+        let hash = self.hash_builder.hash_one(s);
+        self.intern_prehashed(s, hash)
+    }
+
+    /// Interns a batch of strings, reporting how many were already interned.
+    ///
+    /// A string counts as a cache hit when an equal string was interned
+    /// before, either by an earlier call or earlier in the same batch, so
+    /// nothing had to be leaked for it. This measures how effective
+    /// deduplication is for each batch.
+    ///
+    /// The batch stops at the first string that does not fit the budget.
+    /// The strings before it stay interned, so retrying the batch after the
+    /// error only hits the cache for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `strings` - A slice of `String`s to be interned.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the interned `&'static str` in input order and the
+    /// number of cache hits, or the [`BudgetExceeded`] error of the first
+    /// string that would exceed the budget.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let _ = interner.intern("hello");
+    ///
+    /// let batch = vec![String::from("hello"), String::from("world")];
+    /// let (strs, hits) = interner.intern_all(&batch).unwrap();
+    /// assert_eq!(strs, vec!["hello", "world"]);
+    /// assert_eq!(hits, 1);
+    /// ```
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn intern_all(
+        &mut self,
+        strings: &[String],
+    ) -> Result<(Vec<&'static str>, usize), BudgetExceeded> {
+        let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+        let mut hits = 0;
+
+        for string in strings {
+            let unique = self.strings.len();
+            strs.push(self.intern(string)?);
+            if self.strings.len() == unique {
+                hits += 1;
+            }
+        }
+
+        Ok((strs, hits))
+    }
+
+    /// Interns a string slice using a hash computed by the caller.
+    ///
+    /// This avoids hashing `s` again when its hash is already known, for
+    /// example because it was computed for another index upstream. `hash`
+    /// must be the hash of `s` produced by [`Interner::hasher`]; an
+    /// inconsistent hash is not unsafe, but it can cause equal strings to
+    /// miss each other and be leaked twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to be interned.
+    /// * `hash` - The hash of `s`, as computed by `self.hasher().hash_one(s)`.
+    ///
+    /// # Returns
+    ///
+    /// A `&'static str` that is pointer-identical for every call with equal
+    /// content, or a [`BudgetExceeded`] error if `s` is new and leaking it
+    /// would exceed the budget.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::hash::BuildHasher;
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let hash = interner.hasher().hash_one("hello");
+    /// let first = interner.intern_prehashed("hello", hash).unwrap();
+    /// assert!(std::ptr::eq(first, interner.intern("hello").unwrap()));
+    /// ```
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn intern_prehashed(&mut self, s: &str, hash: u64) -> Result<&'static str, BudgetExceeded> {
+        if let Some(&interned) = self.strings.find(hash, |interned| *interned == s) {
+            self.record_hit(s.len());
+            return Ok(interned);
+        }
+
+        if let Some(budget) = self.budget {
+            if self.bytes.saturating_add(s.len()) > budget {
+                return Err(BudgetExceeded {
+                    requested: s.len(),
+                    used: self.bytes,
+                    budget,
+                });
+            }
+        }
+
+        let interned = str_to_static_str(s);
+        let hash_builder = &self.hash_builder;
+        self.strings
+            .insert_unique(hash, interned, |interned| hash_builder.hash_one(interned));
+        self.total = self.total.saturating_add(1);
+        // Every interned string is leaked and stays in memory, so their
+        // combined length always fits in a `usize`.
+        self.bytes += s.len();
+
+        Ok(interned)
+    }
+
+    /// Counts a call that returned an already interned string of `len` bytes.
+    ///
+    /// Hits leak nothing, so unlike `bytes` their totals are not bounded by
+    /// the available memory; they saturate instead of overflowing.
+    fn record_hit(&mut self, len: usize) {
+        self.total = self.total.saturating_add(1);
+        self.bytes_saved = self.bytes_saved.saturating_add(len);
+    }
+
+    /// Interns a string slice, treating strings that differ only in ASCII
+    /// case as equal.
+    ///
+    /// This suits identifiers such as HTTP header names, where
+    /// `"Content-Type"` and `"content-type"` should share one leaked string.
+    /// The first spelling seen for a key is the one that is returned for
+    /// every later case variant; it is not lowercased or otherwise
+    /// canonicalized. Non-ASCII characters are compared exactly.
+    ///
+    /// The first spelling is interned with [`Interner::intern`], so it is
+    /// pointer-identical to what `intern` returns for that exact spelling.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to be interned.
+    ///
+    /// # Returns
+    ///
+    /// A `&'static str` that is pointer-identical for every call whose input
+    /// is equal to it ignoring ASCII case, or a [`BudgetExceeded`] error if
+    /// no case variant of `s` was interned before and leaking `s` would
+    /// exceed the budget.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let first = interner.intern_ascii_case_insensitive("Content-Type").unwrap();
+    /// let second = interner.intern_ascii_case_insensitive("content-type").unwrap();
+    /// assert!(std::ptr::eq(first, second));
+    /// assert_eq!(second, "Content-Type");
+    /// ```
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn intern_ascii_case_insensitive(
+        &mut self,
+        s: &str,
+    ) -> Result<&'static str, BudgetExceeded> {
+        let hash = hash_ascii_lowercase(&self.hash_builder, s);
+        if let Some(&interned) = self
+            .ascii_case_insensitive
+            .find(hash, |interned| interned.eq_ignore_ascii_case(s))
+        {
+            self.record_hit(s.len());
+            return Ok(interned);
+        }
+
+        let interned = self.intern(s)?;
+        let hash_builder = &self.hash_builder;
+        self.ascii_case_insensitive
+            .insert_unique(hash, interned, |interned| {
+                hash_ascii_lowercase(hash_builder, interned)
+            });
+
+        Ok(interned)
+    }
+
+    /// Interns the canonical key of a string slice, treating strings with
+    /// the same key as equal.
+    ///
+    /// `key_fn` maps `s` to its canonical form, such as the trimmed or
+    /// normalized string, and that key is what gets interned and returned.
+    /// Every input with the same key therefore shares one leaked string.
+    /// Unlike [`Interner::intern_ascii_case_insensitive`], which returns
+    /// the first spelling seen, the result is always the key itself, and it
+    /// is pointer-identical to what [`Interner::intern`] returns for the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to be interned.
+    /// * `key_fn` - The function deriving the canonical key of `s`.
+    ///
+    /// # Returns
+    ///
+    /// A `&'static str` copy of `key_fn(s)`, pointer-identical for every
+    /// call whose input has the same key, or a [`BudgetExceeded`] error if
+    /// the key is new and leaking it would exceed the budget.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let trim = |s: &str| s.trim().to_string();
+    /// let first = interner.intern_by("  hello ", trim).unwrap();
+    /// let second = interner.intern_by("hello", trim).unwrap();
+    /// assert!(std::ptr::eq(first, second));
+    /// assert_eq!(first, "hello");
+    /// ```
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn intern_by<F: Fn(&str) -> String>(
+        &mut self,
+        s: &str,
+        key_fn: F,
+    ) -> Result<&'static str, BudgetExceeded> {
+        self.intern(&key_fn(s))
+    }
+
+    /// Looks up a string slice without interning it.
+    ///
+    /// Nothing is leaked and the [`Interner::stats`] are left untouched,
+    /// whether or not the string was interned before.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to look up.
+    ///
+    /// # Returns
+    ///
+    /// The previously interned `&'static str` equal to `s`, or `None` if no
+    /// such string has been interned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// assert_eq!(interner.get("hello"), None);
+    ///
+    /// let interned = interner.intern("hello").unwrap();
+    /// assert!(std::ptr::eq(interner.get("hello").unwrap(), interned));
+    /// ```
+    pub fn get(&self, s: &str) -> Option<&'static str> {
+        let hash = self.hash_builder.hash_one(s);
+        self.strings.find(hash, |interned| *interned == s).copied()
+    }
+
+    /// Returns `true` if a string equal to `s` has been interned.
+    ///
+    /// Like [`Interner::get`], this never leaks anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to look up.
+    pub fn contains(&self, s: &str) -> bool {
+        self.get(s).is_some()
+    }
+
+    /// Returns every distinct interned string, in unspecified order.
+    ///
+    /// The dictionary can be saved, for example by serializing it with the
+    /// `serde` feature, and passed to [`Interner::from_dictionary`] to
+    /// restore the interner in a later run.
+    ///
+    /// # Returns
+    ///
+    /// A vector with each interned `&'static str` exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let _ = interner.intern("hello");
+    /// let _ = interner.intern("hello");
+    /// assert_eq!(interner.to_dictionary(), vec!["hello"]);
+    /// ```
+    pub fn to_dictionary(&self) -> Vec<&'static str> {
+        self.strings.iter().copied().collect()
+    }
+
+    /// Reserves room for at least `additional` more distinct strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - The number of new distinct strings to make room for.
+    pub fn reserve(&mut self, additional: usize) {
+        let hash_builder = &self.hash_builder;
+        self.strings
+            .reserve(additional, |interned| hash_builder.hash_one(interned));
+    }
+
+    /// Returns the number of distinct strings the interner can hold without
+    /// growing its table.
+    pub fn capacity(&self) -> usize {
+        self.strings.capacity()
+    }
+
+    /// Returns the number of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns a summary of everything interned so far.
+    ///
+    /// `total` counts every successful call that interned a string, while
+    /// `unique` and `bytes` describe the strings that were actually leaked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec_string_to_static_str::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let _ = interner.intern("hello");
+    /// let _ = interner.intern("hello");
+    ///
+    /// let stats = interner.stats();
+    /// assert_eq!((stats.total, stats.unique, stats.bytes), (2, 1, 5));
+    /// assert_eq!(stats.bytes_saved, 5);
+    /// ```
+    pub fn stats(&self) -> InternStats {
+        InternStats {
+            total: self.total,
+            unique: self.strings.len(),
+            bytes: self.bytes,
+            bytes_saved: self.bytes_saved,
+        }
+    }
+}
+
+/// Interns every string, leaking only those not seen before.
+///
+/// With a budgeted interner, strings that do not fit the remaining budget
+/// are skipped, while later strings that still fit are interned. Use
+/// [`Interner::intern_all`] to stop at the first string that does not fit
+/// and get its error instead.
+impl<S: BuildHasher> Extend<String> for Interner<S> {
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        for string in iter {
+            let _ = self.intern(&string);
+        }
+    }
+}
+
+/// Interns every string slice, leaking only those not seen before.
+///
+/// With a budgeted interner, strings that do not fit the remaining budget
+/// are skipped, like with `Extend<String>`.
+impl<'a, S: BuildHasher> Extend<&'a str> for Interner<S> {
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            let _ = self.intern(s);
+        }
+    }
+}
+
+/// Hashes `s` as if it had been converted to ASCII lowercase, so that
+/// strings equal under `eq_ignore_ascii_case` hash identically.
+fn hash_ascii_lowercase(hash_builder: &impl BuildHasher, s: &str) -> u64 {
+    let mut hasher = hash_builder.build_hasher();
+
+    for byte in s.bytes() {
+        hasher.write_u8(byte.to_ascii_lowercase());
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn interner_new_is_empty() {
+        let interner = Interner::new();
+
+        assert_eq!(0, interner.len());
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn interner_default_is_empty() {
+        let interner: Interner = Interner::default();
+
+        assert!(interner.is_empty());
+        assert_eq!(InternStats::default(), interner.stats());
+    }
+
+    #[test]
+    fn interner_clone_shares_interned_strings() {
+        let mut interner = Interner::new();
+        let first = interner.intern("string_a").unwrap();
+        let second = interner.intern("string_b").unwrap();
+
+        let mut clone = interner.clone();
+
+        assert_eq!(2, clone.len());
+        assert_eq!(interner.stats(), clone.stats());
+        assert!(std::ptr::eq(first, clone.intern("string_a").unwrap()));
+        assert!(std::ptr::eq(second, clone.intern("string_b").unwrap()));
+    }
+
+    #[test]
+    fn interner_clone_is_independent() {
+        let mut interner = Interner::new();
+        let _ = interner.intern("string_a");
+
+        let mut clone = interner.clone();
+        let _ = clone.intern("string_b");
+
+        assert_eq!(1, interner.len());
+        assert_eq!(2, clone.len());
+    }
+
+    #[test]
+    fn interner_with_capacity_interns_without_growing() {
+        let mut interner = Interner::with_capacity(100);
+        let capacity = interner.capacity();
+
+        let words: Vec<String> = (0..100).map(|i| format!("string_{i}")).collect();
+        let actual: Vec<&'static str> = words
+            .iter()
+            .map(|word| interner.intern(word).unwrap())
+            .collect();
+
+        assert!(capacity >= 100);
+        assert_eq!(words, actual);
+        assert_eq!(capacity, interner.capacity());
+    }
+
+    #[test]
+    fn interner_reserve_keeps_interned_strings() {
+        let mut interner = Interner::new();
+        let first = interner.intern("string_a").unwrap();
+
+        interner.reserve(1000);
+
+        assert!(interner.capacity() >= 1001);
+        assert!(std::ptr::eq(first, interner.intern("string_a").unwrap()));
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn interner_get_before_and_after_interning() {
+        let mut interner = Interner::new();
+
+        assert_eq!(None, interner.get("string_a"));
+        assert!(!interner.contains("string_a"));
+
+        let interned = interner.intern("string_a").unwrap();
+
+        assert!(std::ptr::eq(interned, interner.get("string_a").unwrap()));
+        assert!(interner.contains("string_a"));
+        assert!(!interner.contains("string_b"));
+    }
+
+    #[test]
+    fn interner_get_leaks_nothing() {
+        let mut interner = Interner::new();
+        let _ = interner.intern("string_a");
+        let stats = interner.stats();
+
+        let (_, allocations) = count_allocations(|| {
+            let _ = interner.get("string_a");
+            let _ = interner.get("string_b");
+        });
+
+        assert_eq!(0, allocations);
+        assert_eq!(1, interner.len());
+        assert_eq!(stats, interner.stats());
+    }
+
+    #[test]
+    fn interner_dictionary_round_trip() {
+        let mut interner = Interner::new();
+        for word in ["string_a", "string_b", "string_a", "", "你好"] {
+            let _ = interner.intern(word);
+        }
+
+        let dictionary: Vec<String> = interner
+            .to_dictionary()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut restored = Interner::from_dictionary(&dictionary);
+
+        assert_eq!(4, dictionary.len());
+        assert_eq!(4, restored.len());
+        for word in ["string_a", "string_b", "", "你好"] {
+            let cached = restored.get(word).unwrap();
+            assert!(std::ptr::eq(cached, restored.intern(word).unwrap()));
+        }
+        assert_eq!(4, restored.len());
+    }
+
+    #[test]
+    fn interner_to_dictionary_empty() {
+        let interner = Interner::new();
+
+        assert!(interner.to_dictionary().is_empty());
+    }
+
+    #[test]
+    fn interner_stats_saturate_instead_of_overflowing() {
+        let mut interner = Interner::new();
+        let _ = interner.intern("string_a");
+        interner.total = usize::MAX - 1;
+        interner.bytes_saved = usize::MAX - 4;
+
+        let _ = interner.intern("string_a");
+        let _ = interner.intern("string_a");
+        let _ = interner.intern_ascii_case_insensitive("string_a");
+        let _ = interner.intern_ascii_case_insensitive("STRING_A");
+
+        assert_eq!(usize::MAX, interner.stats().total);
+        assert_eq!(usize::MAX, interner.stats().bytes_saved);
+    }
+
+    #[test]
+    fn interner_intern_returns_content() {
+        let mut interner = Interner::new();
+
+        let actual = interner.intern("string_a");
+
+        assert_eq!(Ok("string_a"), actual);
+    }
+
+    #[test]
+    fn interner_intern_same_word_across_calls() {
+        let mut interner = Interner::new();
+
+        let first = interner.intern("string_a").unwrap();
+        let second = interner.intern(&String::from("string_a")).unwrap();
+
+        assert!(std::ptr::eq(first, second));
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_distinct_words() {
+        let mut interner = Interner::new();
+
+        let first = interner.intern("string_a").unwrap();
+        let second = interner.intern("string_b").unwrap();
+
+        assert!(!std::ptr::eq(first, second));
+        assert_eq!(2, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_all_counts_hits() {
+        let mut interner = Interner::new();
+        let old = interner.intern("string_a").unwrap();
+        let batch = vec![
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_c".to_string(),
+            "string_b".to_string(),
+        ];
+
+        let (actual, hits) = interner.intern_all(&batch).unwrap();
+
+        assert_eq!(batch, actual);
+        assert_eq!(2, hits);
+        assert_eq!(3, interner.len());
+        assert!(std::ptr::eq(old, actual[0]));
+        assert!(std::ptr::eq(actual[1], actual[3]));
+    }
+
+    #[test]
+    fn interner_intern_all_empty_batch() {
+        let mut interner = Interner::new();
+
+        let (actual, hits) = interner.intern_all(&[]).unwrap();
+
+        assert!(actual.is_empty());
+        assert_eq!(0, hits);
+    }
+
+    #[test]
+    fn interner_intern_prehashed_matches_intern() {
+        let mut interner = Interner::new();
+
+        let first = interner.intern("string_a").unwrap();
+        let hash = interner.hasher().hash_one("string_a");
+        let second = interner.intern_prehashed("string_a", hash).unwrap();
+
+        assert!(std::ptr::eq(first, second));
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_prehashed_first() {
+        let mut interner = Interner::new();
+
+        let hash = interner.hasher().hash_one("string_a");
+        let first = interner.intern_prehashed("string_a", hash).unwrap();
+        let second = interner.intern("string_a").unwrap();
+
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn interner_survives_growth() {
+        let mut interner = Interner::new();
+        let words: Vec<String> = (0..1000).map(|i| format!("string_{i}")).collect();
+
+        let first: Vec<&'static str> = words
+            .iter()
+            .map(|word| interner.intern(word).unwrap())
+            .collect();
+        let second: Vec<&'static str> = words
+            .iter()
+            .map(|word| interner.intern(word).unwrap())
+            .collect();
+
+        assert_eq!(words.len(), interner.len());
+        for (first, second) in first.iter().zip(&second) {
+            assert!(std::ptr::eq(*first, *second));
+        }
+    }
+
+    #[test]
+    fn interner_growth_keeps_earlier_pointers() {
+        let mut interner = Interner::new();
+        let first = interner.intern("string_a").unwrap();
+        let (ptr, len) = (first.as_ptr(), first.len());
+
+        let count = if cfg!(miri) { 100 } else { 10_000 };
+        for i in 0..count {
+            let _ = interner.intern(&format!("string_{i}"));
+        }
+
+        let again = interner.intern("string_a").unwrap();
+        assert_eq!(ptr, again.as_ptr());
+        assert_eq!("string_a", first);
+        // SAFETY: interned strings are leaked and never freed or mutated, so
+        // growing the table cannot move or invalidate them.
+        let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+        assert_eq!(b"string_a", bytes);
+    }
+
+    #[test]
+    fn interner_strings_outlive_interner() {
+        let interned = {
+            let mut interner = Interner::new();
+            interner.intern("string_a").unwrap()
+        };
+
+        assert_eq!("string_a", interned);
+    }
+
+    /// A deterministic FNV-1a hasher, standing in for third-party hashers.
+    #[derive(Default)]
+    struct FnvHasher(u64);
+
+    impl Hasher for FnvHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Default)]
+    struct FnvBuildHasher;
+
+    impl BuildHasher for FnvBuildHasher {
+        type Hasher = FnvHasher;
+
+        fn build_hasher(&self) -> FnvHasher {
+            FnvHasher(0xcbf2_9ce4_8422_2325)
+        }
+    }
+
+    #[test]
+    fn interner_with_hasher_deduplicates() {
+        let mut interner = Interner::with_hasher(FnvBuildHasher);
+        let words: Vec<String> = (0..100).map(|i| format!("string_{}", i % 10)).collect();
+
+        let first: Vec<&'static str> = words
+            .iter()
+            .map(|word| interner.intern(word).unwrap())
+            .collect();
+
+        assert_eq!(words, first);
+        assert_eq!(10, interner.len());
+        for (word, interned) in words.iter().zip(&first) {
+            assert!(std::ptr::eq(*interned, interner.intern(word).unwrap()));
+        }
+    }
+
+    #[test]
+    fn interner_with_hasher_uses_given_hasher() {
+        let mut interner = Interner::with_hasher(FnvBuildHasher);
+        let hash = interner.hasher().hash_one("string_a");
+
+        let first = interner.intern_prehashed("string_a", hash).unwrap();
+        let second = interner.intern("string_a").unwrap();
+
+        assert_eq!(FnvBuildHasher.hash_one("string_a"), hash);
+        assert!(std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn interner_default_with_custom_hasher() {
+        let mut interner: Interner<FnvBuildHasher> = Interner::default();
+
+        let first = interner.intern("string_a").unwrap();
+
+        assert!(std::ptr::eq(first, interner.intern("string_a").unwrap()));
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn interner_with_capacity_and_hasher_reserves() {
+        let mut interner = Interner::with_capacity_and_hasher(100, FnvBuildHasher);
+
+        let (_, allocations) = count_allocations(|| {
+            for i in 0..100 {
+                let _ = interner.intern(&format!("string_{i}"));
+            }
+        });
+
+        assert!(interner.capacity() >= 100);
+        // The formatted key and the leaked copy of each string; the table
+        // never grows.
+        assert_eq!(200, allocations);
+    }
+
+    #[test]
+    fn interner_with_budget_and_hasher_enforces_budget() {
+        let mut interner = Interner::with_budget_and_hasher(8, FnvBuildHasher);
+
+        assert_eq!(Ok("string_a"), interner.intern("string_a"));
+        assert_eq!(
+            Err(BudgetExceeded {
+                requested: 8,
+                used: 8,
+                budget: 8,
+            }),
+            interner.intern("string_b")
+        );
+        assert_eq!(Ok("string_a"), interner.intern("string_a"));
+    }
+
+    #[test]
+    fn interner_intern_ascii_case_insensitive_case_variants() {
+        let mut interner = Interner::new();
+
+        let first = interner
+            .intern_ascii_case_insensitive("Content-Type")
+            .unwrap();
+        let second = interner
+            .intern_ascii_case_insensitive("content-type")
+            .unwrap();
+        let third = interner
+            .intern_ascii_case_insensitive("CONTENT-TYPE")
+            .unwrap();
+
+        assert!(std::ptr::eq(first, second));
+        assert!(std::ptr::eq(first, third));
+        assert_eq!("Content-Type", third);
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_ascii_case_insensitive_distinct_words() {
+        let mut interner = Interner::new();
+
+        let first = interner
+            .intern_ascii_case_insensitive("Content-Type")
+            .unwrap();
+        let second = interner
+            .intern_ascii_case_insensitive("Content-Length")
+            .unwrap();
+
+        assert_eq!("Content-Type", first);
+        assert_eq!("Content-Length", second);
+        assert_eq!(2, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_ascii_case_insensitive_shares_exact_spelling() {
+        let mut interner = Interner::new();
+
+        let exact = interner.intern("Accept").unwrap();
+        let insensitive = interner.intern_ascii_case_insensitive("Accept").unwrap();
+        let variant = interner.intern("accept").unwrap();
+
+        assert!(std::ptr::eq(exact, insensitive));
+        assert!(!std::ptr::eq(exact, variant));
+        assert_eq!(2, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_ascii_case_insensitive_non_ascii_exact() {
+        let mut interner = Interner::new();
+
+        let first = interner.intern_ascii_case_insensitive("Ä").unwrap();
+        let second = interner.intern_ascii_case_insensitive("ä").unwrap();
+
+        assert!(!std::ptr::eq(first, second));
+    }
+
+    #[test]
+    fn interner_with_budget_rejects_new_strings_past_budget() {
+        let mut interner = Interner::with_budget(16);
+
+        let first = interner.intern("string_a").unwrap();
+        let second = interner.intern("string_b").unwrap();
+        let actual = interner.intern("string_c");
+
+        assert_eq!(
+            Err(BudgetExceeded {
+                requested: 8,
+                used: 16,
+                budget: 16,
+            }),
+            actual
+        );
+        assert!(std::ptr::eq(first, interner.intern("string_a").unwrap()));
+        assert!(std::ptr::eq(second, interner.intern("string_b").unwrap()));
+        assert_eq!(2, interner.len());
+    }
+
+    #[test]
+    fn interner_with_budget_accepts_smaller_strings_after_rejection() {
+        let mut interner = Interner::with_budget(10);
+
+        interner.intern("string_a").unwrap();
+
+        assert!(interner.intern("string_b").is_err());
+        assert_eq!(Ok("ab"), interner.intern("ab"));
+    }
+
+    #[test]
+    fn interner_intern_unbounded() {
+        let mut interner = Interner::new();
+
+        let actual = interner.intern("string_a");
+
+        assert_eq!(Ok("string_a"), actual);
+    }
+
+    #[test]
+    fn interner_extend_from_vec_string() {
+        let strings = vec![
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_a".to_string(),
+            "string_c".to_string(),
+            "string_b".to_string(),
+        ];
+        let mut interner = Interner::new();
+
+        interner.extend(strings);
+
+        assert_eq!(3, interner.len());
+    }
+
+    #[test]
+    fn interner_extend_from_str_slices() {
+        let mut interner = Interner::new();
+        let first = interner.intern("string_a").unwrap();
+
+        interner.extend(["string_a", "string_b", "string_b"]);
+
+        assert_eq!(2, interner.len());
+        assert!(std::ptr::eq(first, interner.intern("string_a").unwrap()));
+    }
+
+    #[test]
+    fn interner_extend_skips_strings_past_budget() {
+        let mut interner = Interner::with_budget(10);
+
+        interner.extend(["string_a", "string_b", "ab", "string_a"]);
+
+        assert_eq!(2, interner.len());
+        assert!(interner.contains("string_a"));
+        assert!(!interner.contains("string_b"));
+        assert!(interner.contains("ab"));
+    }
+
+    #[test]
+    fn interner_stats_counts_hits_and_misses() {
+        let mut interner = Interner::new();
+
+        let _ = interner.intern("aa");
+        let _ = interner.intern("b");
+        let _ = interner.intern("aa");
+        let _ = interner.intern_ascii_case_insensitive("AA");
+        let _ = interner.intern_ascii_case_insensitive("aA");
+
+        assert_eq!(
+            InternStats {
+                total: 5,
+                unique: 3,
+                bytes: 2 + 1 + 2,
+                bytes_saved: 2 + 2,
+            },
+            interner.stats()
+        );
+    }
+
+    #[test]
+    fn interner_stats_ignores_rejected_strings() {
+        let mut interner = Interner::with_budget(4);
+
+        interner.intern("ab").unwrap();
+        assert!(interner.intern("string_a").is_err());
+
+        assert_eq!(
+            InternStats {
+                total: 1,
+                unique: 1,
+                bytes: 2,
+                bytes_saved: 0,
+            },
+            interner.stats()
+        );
+    }
+
+    #[test]
+    fn interner_debug_shows_count_and_strings() {
+        let mut interner = Interner::new();
+        let _ = interner.intern("string_a");
+
+        let actual = format!("{interner:?}");
+
+        assert_eq!(
+            r#"Interner { len: 1, bytes: 8, budget: None, strings: ["string_a"] }"#,
+            actual
+        );
+    }
+
+    #[test]
+    fn interner_debug_truncates_large_tables() {
+        let mut interner = Interner::with_budget(usize::MAX);
+        for i in 0..1000 {
+            let _ = interner.intern(&format!("string_{i}"));
+        }
+
+        let actual = format!("{interner:?}");
+
+        assert!(actual.starts_with("Interner { len: 1000, "));
+        assert!(actual.contains("budget: Some("));
+        assert!(actual.ends_with(", ...] }"));
+        assert_eq!(
+            crate::static_strs::DEBUG_ENTRIES,
+            actual.matches("string_").count()
+        );
+    }
+
+    #[test]
+    fn interner_intern_by_trimmed_key() {
+        let mut interner = Interner::new();
+        let trim = |s: &str| s.trim().to_string();
+
+        let first = interner.intern_by(" x ", trim).unwrap();
+        let second = interner.intern_by("x", trim).unwrap();
+        let third = interner.intern_by("\tx\n", trim).unwrap();
+
+        assert_eq!("x", first);
+        assert!(std::ptr::eq(first, second));
+        assert!(std::ptr::eq(first, third));
+        assert!(std::ptr::eq(first, interner.intern("x").unwrap()));
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_by_distinct_keys() {
+        let mut interner = Interner::new();
+
+        let first = interner.intern_by("Hello", str::to_lowercase).unwrap();
+        let second = interner.intern_by("World", str::to_lowercase).unwrap();
+
+        assert_eq!("hello", first);
+        assert_eq!("world", second);
+        assert_eq!(2, interner.len());
+    }
+
+    #[test]
+    fn interner_intern_all_stops_at_budget() {
+        let mut interner = Interner::with_budget(16);
+        let strings = vec![
+            "string_a".to_string(),
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_c".to_string(),
+        ];
+
+        let actual = interner.intern_all(&strings);
+
+        assert_eq!(
+            Err(BudgetExceeded {
+                requested: 8,
+                used: 16,
+                budget: 16,
+            }),
+            actual
+        );
+        assert!(interner.contains("string_b"));
+        assert_eq!(
+            Ok((vec!["string_a", "string_a", "string_b"], 3)),
+            interner.intern_all(&strings[..3])
+        );
+    }
+
+    #[test]
+    fn interner_intern_variants_report_budget() {
+        let mut interner = Interner::with_budget(4);
+        let hash = interner.hasher().hash_one("string_a");
+
+        assert!(interner.intern_prehashed("string_a", hash).is_err());
+        assert!(interner.intern_ascii_case_insensitive("string_a").is_err());
+        assert!(interner
+            .intern_by(" string_a ", |s| s.trim().to_string())
+            .is_err());
+        assert!(interner.is_empty());
+
+        assert_eq!(Ok("KEY"), interner.intern_ascii_case_insensitive("KEY"));
+        assert_eq!(Ok("KEY"), interner.intern_ascii_case_insensitive("key"));
+        assert_eq!(1, interner.len());
+    }
+}
@@ -0,0 +1,154 @@
+//! src/io.rs
+
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use crate::leak_str;
+
+/// Reads every line of a reader into a vector of `&'static str`.
+///
+/// Each line is read into its own `String` and leaked in place with
+/// `Box::leak`, so no intermediate `Vec<String>` is built. As with
+/// [`BufRead::lines`], the trailing `\n` or `\r\n` of every line is
+/// stripped.
+///
+/// # Arguments
+///
+/// * `reader` - The buffered reader to read lines from.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the leaked lines, or the first
+/// I/O error encountered. Lines read before an error stay leaked.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use vec_string_to_static_str::lines_to_static_strs;
+///
+/// let static_strs = lines_to_static_strs(Cursor::new("hello\nworld\n")).unwrap();
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn lines_to_static_strs<R: BufRead>(reader: R) -> io::Result<Vec<&'static str>> {
+    let mut strs: Vec<&'static str> = Vec::new();
+
+    for line in reader.lines() {
+        strs.push(leak_str(line?.into_boxed_str()));
+    }
+
+    Ok(strs)
+}
+
+/// Reads every line of a reader into a map from 1-based line numbers to
+/// `&'static str`.
+///
+/// This behaves like [`lines_to_static_strs`], but keys each leaked line by
+/// its line number, which suits resource files such as error message
+/// tables. Blank lines get entries too, so the map has one key per line.
+///
+/// # Arguments
+///
+/// * `reader` - The buffered reader to read lines from.
+///
+/// # Returns
+///
+/// A map from each line number, starting at 1, to the leaked line, or the
+/// first I/O error encountered. Lines read before an error stay leaked.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use vec_string_to_static_str::file_lines_to_static_map;
+///
+/// let messages = file_lines_to_static_map(Cursor::new("not found\ntimed out\n")).unwrap();
+/// assert_eq!(messages[&2], "timed out");
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn file_lines_to_static_map<R: BufRead>(reader: R) -> io::Result<HashMap<usize, &'static str>> {
+    let mut map: HashMap<usize, &'static str> = HashMap::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        map.insert(index + 1, leak_str(line?.into_boxed_str()));
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+
+    #[test]
+    fn lines_to_static_strs_from_cursor() {
+        let actual = lines_to_static_strs(Cursor::new("a\nb\nc\n")).unwrap();
+
+        assert_eq!(vec!["a", "b", "c"], actual);
+    }
+
+    #[test]
+    fn lines_to_static_strs_without_trailing_newline() {
+        let actual = lines_to_static_strs(Cursor::new("a\r\n\nb")).unwrap();
+
+        assert_eq!(vec!["a", "", "b"], actual);
+    }
+
+    #[test]
+    fn lines_to_static_strs_empty_reader() {
+        let actual = lines_to_static_strs(Cursor::new("")).unwrap();
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+
+    #[test]
+    fn lines_to_static_strs_invalid_utf8() {
+        let reader = Cursor::new(b"a\n\xff\n".to_vec());
+
+        let actual = lines_to_static_strs(reader);
+
+        assert_eq!(io::ErrorKind::InvalidData, actual.unwrap_err().kind());
+    }
+
+    #[test]
+    fn lines_to_static_strs_propagates_read_errors() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("failed"))
+            }
+        }
+
+        let actual = lines_to_static_strs(io::BufReader::new(FailingReader));
+
+        assert_eq!(io::ErrorKind::Other, actual.unwrap_err().kind());
+    }
+
+    #[test]
+    fn file_lines_to_static_map_numbers_from_one() {
+        let actual = file_lines_to_static_map(Cursor::new("a\n\nc\n")).unwrap();
+
+        assert_eq!(HashMap::from([(1, "a"), (2, ""), (3, "c")]), actual);
+    }
+
+    #[test]
+    fn file_lines_to_static_map_empty_reader() {
+        let actual = file_lines_to_static_map(Cursor::new("")).unwrap();
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn file_lines_to_static_map_invalid_utf8() {
+        let reader = Cursor::new(b"a\n\xff\n".to_vec());
+
+        let actual = file_lines_to_static_map(reader);
+
+        assert_eq!(io::ErrorKind::InvalidData, actual.unwrap_err().kind());
+    }
+}
@@ -0,0 +1,174 @@
+//! src/iter.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::{leak_str_at, Caller};
+
+/// An iterator that lazily leaks each `String` of an underlying iterator.
+///
+/// This `struct` is created by [`leak_iter`]. Strings are only leaked as
+/// they are yielded, so adapters such as `take` or `filter` limit how much
+/// memory is leaked.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LeakIter<I> {
+    iter: I,
+    caller: Caller,
+}
+
+/// Wraps an iterator of `String`s so that each one is leaked into a
+/// `&'static str` on demand.
+///
+/// Every yielded string is leaked in place with `Box::leak` without being
+/// copied.
+///
+/// # Arguments
+///
+/// * `iter` - An iterator of `String`s to be converted.
+///
+/// # Returns
+///
+/// A [`LeakIter`] yielding `&'static str` references to the leaked strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_iter;
+///
+/// let words = std::iter::repeat_with(|| String::from("word"));
+/// let static_strs: Vec<&'static str> = leak_iter(words).take(2).collect();
+/// assert_eq!(static_strs, vec!["word", "word"]);
+/// ```
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_iter<I: Iterator<Item = String>>(iter: I) -> LeakIter<I> {
+    LeakIter {
+        iter,
+        caller: Caller::here(),
+    }
+}
+
+/// Consumes an iterator of `String`s, leaking each one into a `&'static str`.
+///
+/// This is the iterator-based counterpart of
+/// [`into_static_strs`](crate::into_static_strs). Strings are taken by value
+/// and leaked in place with `Box::leak` without being copied.
+///
+/// # Arguments
+///
+/// * `iter` - Anything that can be turned into an iterator of `String`s.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the leaked strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_collect;
+///
+/// let static_strs = leak_collect((1..=3).map(|i| i.to_string()));
+/// assert_eq!(static_strs, vec!["1", "2", "3"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_collect<I: IntoIterator<Item = String>>(iter: I) -> Vec<&'static str> {
+    leak_iter(iter.into_iter()).collect()
+}
+
+impl<I: Iterator<Item = String>> Iterator for LeakIter<I> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let caller = self.caller;
+        self.iter
+            .next()
+            .map(|string| leak_str_at(string.into_boxed_str(), caller))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = String>> DoubleEndedIterator for LeakIter<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let caller = self.caller;
+        self.iter
+            .next_back()
+            .map(|string| leak_str_at(string.into_boxed_str(), caller))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = String>> ExactSizeIterator for LeakIter<I> {}
+
+impl<I: FusedIterator<Item = String>> FusedIterator for LeakIter<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn leak_iter_from_vec() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual: Vec<&'static str> = leak_iter(strings.into_iter()).collect();
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn leak_iter_only_leaks_consumed_items() {
+        let strings = std::iter::repeat_with(|| String::from("string_a"));
+
+        let (actual, allocations) =
+            count_allocations(|| leak_iter(strings).take(3).collect::<Vec<_>>());
+
+        assert_eq!(vec!["string_a"; 3], actual);
+        // One allocation per produced string plus one for the output vector.
+        assert_eq!(4, allocations);
+    }
+
+    #[test]
+    fn leak_iter_reversed() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual: Vec<&'static str> = leak_iter(strings.into_iter()).rev().collect();
+
+        assert_eq!(vec!["string_b", "string_a"], actual);
+    }
+
+    #[test]
+    fn leak_iter_exact_size() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = leak_iter(strings.into_iter());
+
+        assert_eq!(2, actual.len());
+    }
+
+    #[test]
+    fn leak_collect_from_mapped_range() {
+        let actual = leak_collect((0..3).map(|i| format!("string_{i}")));
+
+        assert_eq!(vec!["string_0", "string_1", "string_2"], actual);
+    }
+
+    #[test]
+    fn leak_collect_from_vec() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = leak_collect(strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn leak_collect_empty_iterator() {
+        let actual = leak_collect(std::iter::empty());
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+}
@@ -1,13 +1,314 @@
 //! src/lib.rs
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+mod arena;
+mod boxed;
+mod bytes;
+#[cfg(feature = "std")]
+mod cached;
+mod chunks;
+#[cfg(feature = "cleanup")]
+mod cleanup;
+mod collections;
+mod cow;
+mod dedup;
+mod error;
+mod ffi;
+#[cfg(feature = "global")]
+mod global;
+mod guard;
+#[cfg(feature = "std")]
+mod interner;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+mod macros;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "std")]
+mod os;
+mod packed;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "std")]
+mod rc_interner;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod shared;
+#[cfg(feature = "debug-leak-sites")]
+mod sites;
+mod sorted;
+mod split;
+mod static_strs;
+#[cfg(test)]
+mod test_alloc;
+mod traits;
+mod transform;
+#[cfg(feature = "unicode")]
+mod unicode;
+#[cfg(feature = "cleanup")]
+mod unleak;
+#[cfg(feature = "warn-on-drop")]
+mod warn;
+
+pub use arena::LeakArena;
+pub use boxed::vec_string_to_boxed_str;
+pub use bytes::{vec_bytes_to_static_str, vec_string_to_static_bytes};
+#[cfg(feature = "std")]
+pub use cached::CachedConverter;
+pub use chunks::for_each_static_chunk;
+#[cfg(feature = "cleanup")]
+pub use cleanup::free_all_leaked;
+#[cfg(all(feature = "cleanup", unix))]
+pub use cleanup::free_all_leaked_at_exit;
+pub use collections::{
+    btreemap_string_to_static_str, nested_vec_string_to_static_str,
+    vec_option_string_to_static_str, vec_pairs_to_static_str, vec_string_to_static_str_enumerated,
+    vec_string_to_static_str_with_lengths, vecdeque_string_to_static_str,
+};
+#[cfg(feature = "std")]
+pub use collections::{hashmap_string_to_static_str, hashset_string_to_static_str};
+pub use cow::{cow_slice_to_static_str, cow_to_static_str};
+#[cfg(feature = "std")]
+pub use dedup::{build_static_str_index, build_static_str_set, is_member};
+pub use dedup::{extend_static_table, leak_dedup_preserve_order};
+pub use error::{BudgetExceeded, LeakLimitError};
+pub use ffi::{leak_to_raw_parts, leak_to_raw_parts_with_strs, vec_string_to_static_cstr};
+#[cfg(feature = "global")]
+pub use global::{global_intern, global_intern_stats};
+pub use guard::LeakGuard;
+#[cfg(feature = "std")]
+pub use interner::{InternStats, Interner};
+#[cfg(feature = "std")]
+pub use io::{file_lines_to_static_map, lines_to_static_strs};
+pub use iter::{leak_collect, leak_iter, LeakIter};
+#[cfg(feature = "metrics")]
+pub use metrics::{leaked_bytes, leaked_count};
+#[cfg(feature = "std")]
+pub use os::{vec_string_to_static_osstr, vec_string_to_static_path};
+pub use packed::{leak_join, vec_string_to_static_str_packed, vec_string_to_static_str_with_spans};
+#[cfg(feature = "rayon")]
+pub use parallel::par_vec_string_to_static_str;
+#[cfg(feature = "std")]
+pub use rc_interner::{CompactingInterner, RcInterner};
+#[cfg(feature = "serde")]
+pub use serde_impls::StaticStrVec;
+pub use shared::{vec_string_to_arc_str, vec_string_to_rc_str};
+#[cfg(feature = "debug-leak-sites")]
+pub use sites::leak_sites;
+pub use sorted::{leak_sorted_by, leak_sorted_dedup};
+pub use split::split_to_static_strs;
+pub use static_strs::StaticStrs;
+pub use traits::IntoStaticStrVec;
+pub use transform::{
+    vec_string_to_static_str_escaped, vec_string_to_static_str_filtered,
+    vec_string_to_static_str_lowercase, vec_string_to_static_str_mapped,
+    vec_string_to_static_str_prefixed, vec_string_to_static_str_suffixed,
+};
+#[cfg(feature = "unicode")]
+pub use unicode::vec_string_to_static_str_nfc;
+#[cfg(feature = "cleanup")]
+pub use unleak::{unleak, unleak_all};
+#[cfg(feature = "warn-on-drop")]
+pub use warn::{vec_string_to_static_str_warn_on_drop, WarnOnDrop};
+
+// Leaked strings are immutable and live forever, so the outputs, and the
+// types that hand them out, can be moved to and shared with any thread.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<&'static str>();
+    assert_send_sync::<Vec<&'static str>>();
+    assert_send_sync::<&'static [&'static str]>();
+    assert_send_sync::<LeakArena>();
+    assert_send_sync::<LeakGuard>();
+    assert_send_sync::<StaticStrs>();
+    #[cfg(feature = "std")]
+    assert_send_sync::<Interner>();
+    #[cfg(feature = "std")]
+    assert_send_sync::<CachedConverter>();
+};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::format;
+    pub use alloc::string::String;
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+
+    /// Leaks an owned `String` without copying it, for `leak_format!`.
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub fn leak_string(string: String) -> &'static str {
+        crate::leak_str(string.into_boxed_str())
+    }
+}
+
+/// The call site that leaks are recorded under when the `debug-leak-sites`
+/// feature is enabled.
+///
+/// `#[track_caller]` does not reach into closures or lazy iterators, so
+/// conversions that leak from there capture their caller up front with
+/// [`Caller::here`] and pass it along. Without the feature it is empty.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Caller {
+    #[cfg(feature = "debug-leak-sites")]
+    location: &'static core::panic::Location<'static>,
+}
+
+impl Caller {
+    /// Captures the location of the caller.
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    pub(crate) fn here() -> Self {
+        Self {
+            #[cfg(feature = "debug-leak-sites")]
+            location: core::panic::Location::caller(),
+        }
+    }
+
+    /// Records `bytes` leaked bytes under this call site, for leaks that do
+    /// not go through [`leak_boxed_at`].
+    #[cfg_attr(not(feature = "debug-leak-sites"), allow(unused_variables))]
+    pub(crate) fn record(self, bytes: usize) {
+        #[cfg(feature = "debug-leak-sites")]
+        sites::record_site(self.location, bytes);
+    }
+}
+
+/// Leaks a boxed value of `bytes` bytes, recording it in the leak metrics
+/// when the `metrics` feature is enabled, and under the call site when the
+/// `debug-leak-sites` feature is enabled.
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub(crate) fn leak_boxed<T: ?Sized>(boxed: Box<T>, bytes: usize) -> &'static T {
+    leak_boxed_at(boxed, bytes, Caller::here())
+}
+
+/// Leaks a boxed value of `bytes` bytes like [`leak_boxed`], recording it
+/// under `caller` instead of the current call site.
+pub(crate) fn leak_boxed_at<T: ?Sized>(boxed: Box<T>, bytes: usize, caller: Caller) -> &'static T {
+    #[cfg(feature = "metrics")]
+    metrics::record_leak(1, bytes);
+    caller.record(bytes);
+
+    leak_unmetered(boxed)
+}
+
+/// Leaks a boxed value without recording it in the leak metrics, for
+/// callers that record their leaks themselves or leak no string data.
+///
+/// With the `cleanup` feature enabled, the allocation is registered so that
+/// `free_all_leaked` can free it.
+pub(crate) fn leak_unmetered<T: ?Sized>(boxed: Box<T>) -> &'static mut T {
+    let ptr = Box::into_raw(boxed);
+
+    #[cfg(feature = "cleanup")]
+    // SAFETY: `ptr` comes from `Box::into_raw` and is only freed by the
+    // registry, either all at once or through `unleak`.
+    unsafe {
+        cleanup::register(ptr)
+    };
+
+    // SAFETY: `ptr` comes from `Box::into_raw`, so it is valid and is only
+    // ever freed by `unleak` or `free_all_leaked`, whose callers guarantee
+    // it is no longer used.
+    unsafe { &mut *ptr }
+}
+
+/// Leaks a boxed string slice.
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub(crate) fn leak_str(s: Box<str>) -> &'static str {
+    leak_str_at(s, Caller::here())
+}
+
+/// Leaks a boxed string slice, recording it under `caller`.
+pub(crate) fn leak_str_at(s: Box<str>, caller: Caller) -> &'static str {
+    let bytes = s.len();
+    leak_boxed_at(s, bytes, caller)
+}
+
+/// Leaks a boxed byte slice.
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub(crate) fn leak_bytes(bytes: Box<[u8]>) -> &'static [u8] {
+    let len = bytes.len();
+    leak_boxed(bytes, len)
+}
+
+/// Converts a `&str` into a `&'static str`.
+///
+/// This function leaks memory as it uses `Box::leak` to create a
+/// static string slice from an owned copy of the input. Use with caution.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be converted.
+///
+/// # Returns
+///
+/// A `&'static str` reference to a copy of the input string slice.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::str_to_static_str;
+///
+/// let buffer = String::from("hello world");
+/// let static_str = str_to_static_str(&buffer[0..5]);
+/// assert_eq!(static_str, "hello");
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn str_to_static_str(s: &str) -> &'static str {
+    leak_str(s.into())
+}
+
+/// Converts a `String` into a `&'static str`.
+///
+/// This function leaks memory as it uses `Box::leak` to create a
+/// static string slice from a copy of the input string. Use with caution.
+///
+/// # Arguments
+///
+/// * `s` - The `String` to be converted.
+///
+/// # Returns
+///
+/// A `&'static str` reference to a copy of the input string.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::string_to_static_str;
+///
+/// let string = String::from("hello");
+/// let static_str = string_to_static_str(&string);
+/// assert_eq!(static_str, "hello");
+/// ```
+#[allow(clippy::ptr_arg)]
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn string_to_static_str(s: &String) -> &'static str {
+    str_to_static_str(s)
+}
+
 /// Converts a vector of `String`s into a vector of `&'static str`.
 ///
 /// This function leaks memory as it uses `Box::leak` to create
-/// static string slices from the input strings. Use with caution.
+/// static string slices from the input strings. Use with caution: if the
+/// results do not need to outlive `strings`, [`vec_string_as_strs`] borrows
+/// them instead and leaks nothing.
 ///
 /// # Arguments
 ///
-/// * `strings` - A vector of `String`s to be converted.
+/// * `strings` - A slice of `String`s to be converted.
 ///
 /// # Returns
 ///
@@ -22,122 +323,1216 @@
 /// let static_strs = vec_string_to_static_str(&strings);
 /// assert_eq!(static_strs, vec!["hello", "world"]);
 /// ```
-pub fn vec_string_to_static_str(strings: &Vec<String>) -> Vec<&'static str> {
-    let mut strs: Vec<&'static str> = Vec::new();
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str(strings: &[String]) -> Vec<&'static str> {
+    to_static_strs(strings)
+}
+
+/// An empty table of `&'static str`, for callers that need a `const`
+/// placeholder.
+///
+/// The functions returning a `&'static [&'static str]` return this slice
+/// for empty inputs, without allocating or leaking anything.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{vec_string_to_static_slice, EMPTY};
+///
+/// const NO_NAMES: &[&str] = EMPTY;
+/// assert_eq!(vec_string_to_static_slice(&[]), NO_NAMES);
+/// ```
+pub const EMPTY: &[&str] = &[];
+
+/// Converts a vector of `String`s into a `&'static [&'static str]`.
+///
+/// This function leaks both the strings and the slice holding them, which
+/// makes the result usable as a long-lived table, for example one stored in
+/// a `static`. Use with caution. An empty input returns [`EMPTY`] without
+/// leaking anything.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A leaked slice of `&'static str` references to copies of the input
+/// strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_slice;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let table: &'static [&'static str] = vec_string_to_static_slice(&strings);
+/// assert_eq!(table, ["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_slice(strings: &[String]) -> &'static [&'static str] {
+    if strings.is_empty() {
+        return EMPTY;
+    }
+
+    leak_unmetered(vec_string_to_static_str(strings).into_boxed_slice())
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, refusing
+/// to leak more than `max_bytes` bytes in total.
+///
+/// The combined byte length of the input is checked before anything is
+/// leaked, so on failure no memory is leaked at all. This guards against
+/// leaking unbounded amounts of memory when processing untrusted input.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+/// * `max_bytes` - The maximum number of string bytes that may be leaked.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the input strings,
+/// or a [`LeakLimitError`] if the input is larger than `max_bytes`.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::try_vec_string_to_static_str;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// assert!(try_vec_string_to_static_str(&strings, 10).is_ok());
+/// assert!(try_vec_string_to_static_str(&strings, 9).is_err());
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn try_vec_string_to_static_str(
+    strings: &[String],
+    max_bytes: usize,
+) -> Result<Vec<&'static str>, LeakLimitError> {
+    let requested = strings
+        .iter()
+        .fold(0usize, |total, string| total.saturating_add(string.len()));
+
+    if requested > max_bytes {
+        return Err(LeakLimitError {
+            requested,
+            limit: max_bytes,
+        });
+    }
+
+    Ok(vec_string_to_static_str(strings))
+}
+
+/// Converts a slice of any string-like values into a vector of `&'static str`.
+///
+/// This is the generic form of [`vec_string_to_static_str`] and accepts
+/// anything implementing `AsRef<str>`, such as `&str`, `String`, `Box<str>`
+/// or `Cow<str>`. Each element is copied and leaked with `Box::leak`.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of string-like values to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::to_static_strs;
+///
+/// let strings = vec!["hello", "world"];
+/// let static_strs = to_static_strs(&strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn to_static_strs<S: AsRef<str>>(strings: &[S]) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(str_to_static_str(string.as_ref()));
+    }
+
+    strs
+}
+
+/// Converts a slice of string slices into a vector of `&'static str`.
+///
+/// This is the `&str` counterpart of [`vec_string_to_static_str`], for
+/// inputs that are already borrowed, so no intermediate `Vec<String>` has to
+/// be built. Each string slice is copied and leaked with `Box::leak`.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of string slices to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::slice_str_to_static_str;
+///
+/// let args = String::from("--verbose --color");
+/// let borrowed: Vec<&str> = args.split(' ').collect();
+/// let static_strs = slice_str_to_static_str(&borrowed);
+/// drop(args);
+/// assert_eq!(static_strs, vec!["--verbose", "--color"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn slice_str_to_static_str(strings: &[&str]) -> Vec<&'static str> {
+    to_static_strs(strings)
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`,
+/// consuming the input.
+///
+/// Unlike [`vec_string_to_static_str`], this function takes ownership of
+/// the strings and leaks each one directly with `Box::leak`, so no copy
+/// of the string data is made. Use this when the original strings are no
+/// longer needed.
+///
+/// Each string is converted with `String::into_boxed_str` before it is
+/// leaked, which releases any spare capacity, so only the string contents
+/// stay allocated.
+///
+/// The output is a new vector allocated with exactly `strings.len()`
+/// elements of capacity. Collecting from `strings.into_iter()` could let
+/// the standard library reuse the input's allocation instead, but that is
+/// an optimization rather than a guarantee, and since a `&str` is smaller
+/// than a `String` the reused buffer would keep about half again the
+/// capacity the output needs.
+///
+/// # Arguments
+///
+/// * `strings` - A vector of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the leaked strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::into_static_strs;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = into_static_strs(strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn into_static_strs(strings: Vec<String>) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(leak_str(string.into_boxed_str()));
+    }
+
+    strs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str` by
+/// leaking the whole vector at once.
+///
+/// Rather than leaking every string separately, this leaks the input
+/// vector's storage with `Vec::leak` and borrows each `String` in it. That
+/// is a single leak instead of one per string, but note that any spare
+/// capacity of the vector and of each string is leaked along with it.
+///
+/// # Arguments
+///
+/// * `strings` - A vector of `String`s to be leaked.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the strings inside the leaked
+/// vector.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_vec_as_static_strs;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = leak_vec_as_static_strs(strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(not(feature = "cleanup"), allow(unused_mut))]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_vec_as_static_strs(mut strings: Vec<String>) -> Vec<&'static str> {
+    let bytes: usize = strings.iter().map(String::len).sum();
+    #[cfg(feature = "metrics")]
+    metrics::record_leak(strings.len(), bytes);
+    Caller::here().record(bytes);
+
+    #[cfg(feature = "cleanup")]
+    cleanup::register_strings(&mut strings);
+
+    let leaked: &'static [String] = strings.leak();
+    let mut strs: Vec<&'static str> = Vec::with_capacity(leaked.len());
+
+    for string in leaked {
+        strs.push(string.as_str());
+    }
+
+    strs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`,
+/// consuming the input and reporting allocation failure instead of aborting.
+///
+/// The output vector is reserved up front with `Vec::try_reserve_exact`, so
+/// if that allocation fails the error is returned and nothing is leaked.
+/// Leaking the strings themselves cannot fail, but converting each `String`
+/// into a `Box<str>` may reallocate to drop spare capacity, and such an
+/// allocation failure still aborts the process as usual.
+///
+/// # Arguments
+///
+/// * `strings` - A vector of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the leaked strings, or a
+/// `TryReserveError` if the output vector could not be allocated.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::try_into_static_strs;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = try_into_static_strs(strings).unwrap();
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn try_into_static_strs(strings: Vec<String>) -> Result<Vec<&'static str>, TryReserveError> {
+    let mut strs = try_vec_with_capacity(strings.len())?;
+
+    for string in strings {
+        strs.push(leak_str(string.into_boxed_str()));
+    }
+
+    Ok(strs)
+}
+
+fn try_vec_with_capacity<T>(capacity: usize) -> Result<Vec<T>, TryReserveError> {
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(capacity)?;
+
+    Ok(vec)
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`,
+/// leaking each distinct value only once.
+///
+/// Equal input strings share a single leaked allocation, so repeated
+/// values such as category labels do not waste memory. The output has the
+/// same length and order as the input.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references, where equal strings point to
+/// the same leaked allocation.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::intern_static_strs;
+///
+/// let strings = vec![String::from("a"), String::from("b"), String::from("a")];
+/// let static_strs = intern_static_strs(&strings);
+/// assert_eq!(static_strs, vec!["a", "b", "a"]);
+/// assert!(std::ptr::eq(static_strs[0], static_strs[2]));
+/// ```
+#[cfg(feature = "std")]
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn intern_static_strs(strings: &[String]) -> Vec<&'static str> {
+    intern_static_strs_reported(strings).0
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`,
+/// leaking each distinct value only once, and reports how much leaking the
+/// deduplication avoided.
+///
+/// This behaves like [`intern_static_strs`], but also returns an
+/// [`InternStats`] summary of the input, which helps decide whether
+/// interning is worthwhile for a given dataset.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references, where equal strings point to
+/// the same leaked allocation, and the statistics of the conversion.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::intern_static_strs_reported;
+///
+/// let strings = vec![String::from("ab"), String::from("c"), String::from("ab")];
+/// let (static_strs, stats) = intern_static_strs_reported(&strings);
+/// assert_eq!(static_strs, vec!["ab", "c", "ab"]);
+/// assert_eq!((stats.total, stats.unique, stats.bytes_saved), (3, 2, 2));
+/// ```
+#[cfg(feature = "std")]
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn intern_static_strs_reported(strings: &[String]) -> (Vec<&'static str>, InternStats) {
+    let mut interned: HashMap<&str, &'static str> = HashMap::new();
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+    let mut bytes_saved = 0;
+
+    for string in strings {
+        let leaked = match interned.get(string.as_str()) {
+            Some(&leaked) => {
+                bytes_saved += string.len();
+                leaked
+            }
+            None => {
+                let leaked = str_to_static_str(string);
+                interned.insert(string, leaked);
+                leaked
+            }
+        };
+        strs.push(leaked);
+    }
+
+    let stats = InternStats {
+        total: strings.len(),
+        unique: interned.len(),
+        bytes: interned.keys().map(|s| s.len()).sum(),
+        bytes_saved,
+    };
+
+    (strs, stats)
+}
+
+/// Borrows a vector of `String`s as a vector of `&str` without leaking.
+///
+/// This is the leak-free default, and the function to reach for whenever
+/// the results do not need to outlive the input: most code that wants a
+/// uniform `&str` view, for example to pass to an API taking `&[&str]`,
+/// needs no `'static` lifetime at all. Only leak with
+/// [`vec_string_to_static_str`] and friends when the strings must really
+/// live for the rest of the program.
+///
+/// It is also the safe counterpart of `unsafe_vec_string_to_static_str`:
+/// the returned references point at the input strings, and their lifetime
+/// is tied to the borrow of `strings`, so the compiler rejects any use after
+/// the input is dropped.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be borrowed.
+///
+/// # Returns
+///
+/// A vector of `&str` references into the input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_as_strs;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let strs = vec_string_as_strs(&strings);
+/// assert_eq!(strs, vec!["hello", "world"]);
+/// ```
+pub fn vec_string_as_strs(strings: &[String]) -> Vec<&str> {
+    let mut strs: Vec<&str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(string.as_str());
+    }
+
+    strs
+}
+
+/// Copies a slice of `&'static str` back into a vector of owned `String`s.
+///
+/// This is the inverse of [`vec_string_to_static_str`], and leaks nothing:
+/// the leaked strings stay where they are, and the returned `String`s are
+/// fresh copies that are freed as usual.
+///
+/// # Arguments
+///
+/// * `strs` - A slice of `&'static str` to be copied.
+///
+/// # Returns
+///
+/// A vector of `String`s with the same contents, in the same order.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{static_strs_to_vec_string, vec_string_to_static_str};
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = vec_string_to_static_str(&strings);
+/// assert_eq!(static_strs_to_vec_string(&static_strs), strings);
+/// ```
+pub fn static_strs_to_vec_string(strs: &[&'static str]) -> Vec<String> {
+    let mut strings: Vec<String> = Vec::with_capacity(strs.len());
+
+    for &s in strs {
+        strings.push(String::from(s));
+    }
+
+    strings
+}
+
+#[cfg(feature = "unsafe")]
+/// Unsafely converts a `&str` into a `&'static str`.
+///
+/// This function uses `core::mem::transmute` to convert the string slice
+/// into a static string slice without leaking memory. The result still
+/// borrows from `s`, despite its `'static` type.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be converted.
+///
+/// # Returns
+///
+/// A `&'static str` reference to the same bytes as the input.
+///
+/// # Safety
+///
+/// The caller must guarantee that the data `s` borrows from outlives every
+/// use of the returned reference, and is not mutated in the meantime. Using
+/// the reference after its owner is dropped or mutated is undefined
+/// behavior. If the data does not need to be `'static`, prefer keeping the
+/// original borrow, or leaking with [`str_to_static_str`].
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::unsafe_str_to_static_str;
+///
+/// let buffer = String::from("hello world");
+/// // SAFETY: `buffer` outlives `static_str`.
+/// let static_str = unsafe { unsafe_str_to_static_str(&buffer[0..5]) };
+/// assert_eq!(static_str, "hello");
+/// ```
+pub unsafe fn unsafe_str_to_static_str(s: &str) -> &'static str {
+    // SAFETY: the caller guarantees that `s` outlives all uses of the result.
+    unsafe { core::mem::transmute::<&str, &'static str>(s) }
+}
+
+#[cfg(feature = "unsafe")]
+/// Unsafely converts a `String` into a `&'static str`.
+///
+/// This function uses `core::mem::transmute` to convert the string slice
+/// into a static string slice without leaking memory. The result still
+/// borrows from `s`, despite its `'static` type.
+///
+/// # Arguments
+///
+/// * `s` - The `String` to be converted.
+///
+/// # Returns
+///
+/// A `&'static str` reference to the input string.
+///
+/// # Safety
+///
+/// The caller must guarantee that `s` is neither dropped nor mutated while
+/// the returned reference is in use. Using the reference afterwards is
+/// undefined behavior. If the data does not need to be `'static`, prefer
+/// `s.as_str()`, or leaking with [`string_to_static_str`].
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::unsafe_string_to_static_str;
+///
+/// let string = String::from("hello");
+/// // SAFETY: `string` outlives `static_str`.
+/// let static_str = unsafe { unsafe_string_to_static_str(&string) };
+/// assert_eq!(static_str, "hello");
+/// ```
+#[allow(clippy::ptr_arg)]
+pub unsafe fn unsafe_string_to_static_str(s: &String) -> &'static str {
+    // SAFETY: forwarded to the caller.
+    unsafe { unsafe_str_to_static_str(s) }
+}
+
+#[cfg(feature = "unsafe")]
+/// Unsafely converts a vector of `String`s into a vector of `&'static str`.
+///
+/// This function uses `core::mem::transmute` to convert string slices
+/// into static string slices without leaking memory. The results still
+/// borrow from `strings`, despite their `'static` type.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the input strings.
+///
+/// # Safety
+///
+/// The caller must guarantee that none of the input strings is dropped or
+/// mutated while any of the returned references is in use. In particular,
+/// dropping the input vector while keeping the result makes every element
+/// dangle, and using them is undefined behavior. [`vec_string_as_strs`]
+/// returns the same references with a compiler-checked lifetime and should
+/// be preferred whenever the result does not need to be `'static`.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::unsafe_vec_string_to_static_str;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// // SAFETY: `strings` outlives `static_strs`.
+/// let static_strs = unsafe { unsafe_vec_string_to_static_str(&strings) };
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+pub unsafe fn unsafe_vec_string_to_static_str(strings: &[String]) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        // SAFETY: forwarded to the caller.
+        strs.push(unsafe { unsafe_string_to_static_str(string) });
+    }
+
+    strs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+    use std::borrow::Cow;
+    use std::sync::OnceLock;
+
+    #[test]
+    fn str_to_static_str_from_literal() {
+        let actual = str_to_static_str("string_a");
+
+        assert_eq!("string_a", actual);
+    }
+
+    #[test]
+    fn str_to_static_str_from_subslice() {
+        let string = String::from("hello world");
+
+        let actual = str_to_static_str(&string[0..5]);
+
+        assert_eq!("hello", actual);
+    }
+
+    #[test]
+    fn str_to_static_str_outlives_source() {
+        let string = String::from("hello world");
+
+        let actual = str_to_static_str(&string[6..]);
+        drop(string);
+
+        assert_eq!("world", actual);
+    }
+
+    #[test]
+    fn string_to_static_str_empty_string() {
+        let string = String::new();
+
+        let actual = string_to_static_str(&string);
+
+        assert_eq!("", actual);
+    }
+
+    #[test]
+    fn string_to_static_str_ascii() {
+        let string = String::from("string_a");
+
+        let actual = string_to_static_str(&string);
+
+        assert_eq!("string_a", actual);
+    }
+
+    #[test]
+    fn string_to_static_str_multibyte() {
+        let string = String::from("你好，世界！");
+
+        let actual = string_to_static_str(&string);
+
+        assert_eq!("你好，世界！", actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_from_literals() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let actual = vec_string_to_static_str(&strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_from_dynamic_allocation() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = vec_string_to_static_str(&strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+    #[test]
+    fn vec_string_to_static_str_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = vec_string_to_static_str(&strings);
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_mixed_content() {
+        let strings = vec!["".to_string(), "a".to_string(), "longer string".to_string()];
+
+        let actual = vec_string_to_static_str(&strings);
+
+        assert_eq!(vec!["", "a", "longer string"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_subslice() {
+        let strings = [
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_c".to_string(),
+            "string_d".to_string(),
+        ];
+
+        let actual = vec_string_to_static_str(&strings[1..3]);
+
+        assert_eq!(vec!["string_b", "string_c"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_special_characters() {
+        let strings = vec![
+            "hello, world!".to_string(),
+            "你好，世界！".to_string(),
+            "こんにちは、世界！".to_string(),
+            "안녕하세요, 세계!".to_string(),
+        ];
+
+        let actual = vec_string_to_static_str(&strings);
+
+        assert_eq!(
+            vec![
+                "hello, world!",
+                "你好，世界！",
+                "こんにちは、世界！",
+                "안녕하세요, 세계!"
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn vec_string_to_static_str_moves_across_threads() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+        let static_strs = vec_string_to_static_str(&strings);
+        let shared = static_strs[0];
+
+        let actual = std::thread::spawn(move || {
+            drop(strings);
+            static_strs.concat()
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!("string_astring_b", actual);
+        assert_eq!("string_a", shared);
+    }
+
+    #[test]
+    fn vec_string_to_static_slice_from_dynamic_allocation() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = vec_string_to_static_slice(&strings);
+
+        assert_eq!(["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_slice_empty_vector() {
+        let strings: Vec<String> = Vec::new();
 
-    for string in strings {
-        strs.push(Box::leak(string.clone().into_boxed_str()));
+        let (actual, allocations) = count_allocations(|| vec_string_to_static_slice(&strings));
+
+        assert_eq!(EMPTY, actual);
+        assert_eq!(0, allocations);
     }
 
-    strs
-}
+    #[test]
+    fn vec_string_to_static_str_empty_vector_does_not_allocate() {
+        let strings: Vec<String> = Vec::new();
 
-#[cfg(feature = "unsafe")]
-/// Unsafely converts a vector of `String`s into a vector of `&'static str`.
-///
-/// This function uses `std::mem::transmute` to convert string slices
-/// into static string slices without leaking memory. Use this function
-/// only when you are certain of the lifetime safety.
-///
-/// # Arguments
-///
-/// * `strings` - A vector of `String`s to be converted.
-///
-/// # Returns
-///
-/// A vector of `&'static str` references to the input strings.
-///
-/// # Safety
-///
-/// This function is unsafe because it extends the lifetime of the string
-/// slices to `'static`, which can cause undefined behavior if not used correctly.
-///
-/// # Example
-///
-/// ```
-/// use vec_string_to_static_str::unsafe_vec_string_to_static_str;
-///
-/// let strings = vec![String::from("hello"), String::from("world")];
-/// let static_strs = unsafe_vec_string_to_static_str(&strings);
-/// assert_eq!(static_strs, vec!["hello", "world"]);
-/// ```
-pub fn unsafe_vec_string_to_static_str(strings: &Vec<String>) -> Vec<&'static str> {
-    let mut strs: Vec<&'static str> = Vec::new();
+        let (actual, allocations) = count_allocations(|| vec_string_to_static_str(&strings));
 
-    for string in strings {
-        strs.push(unsafe { std::mem::transmute::<&str, &'static str>(string.as_str()) });
+        assert!(actual.is_empty());
+        assert_eq!(0, allocations);
     }
 
-    strs
-}
+    #[test]
+    fn vec_string_to_static_slice_is_static() {
+        static TABLE: OnceLock<&'static [&'static str]> = OnceLock::new();
+        let strings = vec![String::from("string_a"), String::from("string_b")];
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let actual = TABLE.get_or_init(|| vec_string_to_static_slice(&strings));
+        drop(strings);
+
+        assert_eq!(["string_a", "string_b"], *actual);
+    }
 
     #[test]
-    fn vec_string_to_static_str_from_literals() {
-        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+    fn try_vec_string_to_static_str_under_limit() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
 
-        let actual = vec_string_to_static_str(&strings);
+        let actual = try_vec_string_to_static_str(&strings, 16);
+
+        assert_eq!(Ok(vec!["string_a", "string_b"]), actual);
+    }
+
+    #[test]
+    fn try_vec_string_to_static_str_over_limit() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let (actual, allocations) =
+            count_allocations(|| try_vec_string_to_static_str(&strings, 15));
+
+        assert_eq!(
+            Err(LeakLimitError {
+                requested: 16,
+                limit: 15
+            }),
+            actual
+        );
+        assert_eq!(0, allocations);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_outlives_output_vector() {
+        let strings = vec!["string_a".to_string(), "".to_string(), "你好".to_string()];
+        let static_strs = vec_string_to_static_str(&strings);
+        let raw_parts: Vec<(*const u8, usize)> =
+            static_strs.iter().map(|s| (s.as_ptr(), s.len())).collect();
+
+        let moved = static_strs;
+        let first = moved[0];
+        drop(moved);
+        drop(strings);
+
+        assert_eq!("string_a", first);
+        for (&(ptr, len), expected) in raw_parts.iter().zip(["string_a", "", "你好"]) {
+            // SAFETY: the leaked bytes are never freed or mutated, so they
+            // stay readable after the vector referencing them is dropped.
+            let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+            assert_eq!(expected.as_bytes(), bytes);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_allocates_output_once() {
+        // Miri is far slower than native execution, so it checks fewer strings.
+        let len = if cfg!(miri) { 100 } else { 100_000 };
+        let strings: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+
+        let (actual, allocations) = count_allocations(|| vec_string_to_static_str(&strings));
+
+        assert_eq!(strings, actual);
+        // One allocation per leaked string plus a single one for the output.
+        assert_eq!(strings.len() + 1, allocations);
+    }
+
+    #[test]
+    fn to_static_strs_from_str_slices() {
+        let strings = vec!["string_a", "string_b"];
+
+        let actual = to_static_strs(&strings);
 
         assert_eq!(vec!["string_a", "string_b"], actual);
     }
 
     #[test]
-    fn vec_string_to_static_str_from_dynamic_allocation() {
+    fn to_static_strs_from_strings() {
         let strings = vec![String::from("string_a"), String::from("string_b")];
 
-        let actual = vec_string_to_static_str(&strings);
+        let actual = to_static_strs(&strings);
 
         assert_eq!(vec!["string_a", "string_b"], actual);
     }
+
     #[test]
-    fn vec_string_to_static_str_empty_vector() {
+    fn to_static_strs_from_boxed_strs() {
+        let strings: Vec<Box<str>> = vec!["string_a".into(), "string_b".into()];
+
+        let actual = to_static_strs(&strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn to_static_strs_from_cows() {
+        let strings: Vec<Cow<str>> = vec![
+            Cow::Borrowed("string_a"),
+            Cow::Owned(String::from("string_b")),
+        ];
+
+        let actual = to_static_strs(&strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn slice_str_to_static_str_from_literals() {
+        let actual = slice_str_to_static_str(&["x", "y"]);
+
+        assert_eq!(vec!["x", "y"], actual);
+    }
+
+    #[test]
+    fn slice_str_to_static_str_outlives_source() {
+        fn require_static(strs: Vec<&'static str>) -> Vec<&'static str> {
+            strs
+        }
+        let source = String::from("x y");
+        let borrowed: Vec<&str> = source.split(' ').collect();
+
+        let actual = require_static(slice_str_to_static_str(&borrowed));
+        drop(borrowed);
+        drop(source);
+
+        assert_eq!(vec!["x", "y"], actual);
+    }
+
+    #[test]
+    fn into_static_strs_matches_cloning_version() {
+        let strings = vec!["".to_string(), "a".to_string(), "你好，世界！".to_string()];
+
+        let expected = vec_string_to_static_str(&strings);
+        let actual = into_static_strs(strings);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn into_static_strs_outlives_output_vector() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+        let static_strs = into_static_strs(strings);
+        let pointers: Vec<*const u8> = static_strs.iter().map(|s| s.as_ptr()).collect();
+        let copies: Vec<&'static str> = static_strs.clone();
+
+        drop(static_strs);
+
+        assert_eq!(vec!["string_a", "string_b"], copies);
+        for (s, ptr) in copies.iter().zip(pointers) {
+            assert_eq!(ptr, s.as_ptr());
+        }
+    }
+
+    #[test]
+    fn into_static_strs_empty_vector() {
         let strings: Vec<String> = Vec::new();
 
-        let actual = vec_string_to_static_str(&strings);
+        let actual = into_static_strs(strings);
 
         assert_eq!(Vec::<&'static str>::new(), actual);
     }
 
     #[test]
-    fn vec_string_to_static_str_mixed_content() {
-        let strings = vec!["".to_string(), "a".to_string(), "longer string".to_string()];
+    fn leak_vec_as_static_strs_matches_cloning_version() {
+        let strings = vec!["".to_string(), "a".to_string(), "你好，世界！".to_string()];
 
-        let actual = vec_string_to_static_str(&strings);
+        let expected = vec_string_to_static_str(&strings);
+        let actual = leak_vec_as_static_strs(strings);
 
-        assert_eq!(vec!["", "a", "longer string"], actual);
+        assert_eq!(expected, actual);
     }
 
     #[test]
-    fn vec_string_to_static_str_special_characters() {
+    fn leak_vec_as_static_strs_points_into_original_strings() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+        let pointers: Vec<*const u8> = strings.iter().map(|s| s.as_ptr()).collect();
+
+        let actual = leak_vec_as_static_strs(strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+        for (pointer, leaked) in pointers.iter().zip(&actual) {
+            assert_eq!(*pointer, leaked.as_ptr());
+        }
+    }
+
+    #[test]
+    fn into_static_strs_does_not_copy_strings() {
         let strings = vec![
-            "hello, world!".to_string(),
-            "你好，世界！".to_string(),
-            "こんにちは、世界！".to_string(),
-            "안녕하세요, 세계!".to_string(),
+            String::from("string_a"),
+            String::from("string_b"),
+            String::from("string_c"),
+            String::from("string_d"),
         ];
+        let pointers: Vec<*const u8> = strings.iter().map(|s| s.as_ptr()).collect();
 
-        let actual = vec_string_to_static_str(&strings);
+        let actual = into_static_strs(strings);
+
+        assert_eq!(vec!["string_a", "string_b", "string_c", "string_d"], actual);
+        assert_eq!(actual.len(), actual.capacity());
+        for (pointer, leaked) in pointers.iter().zip(&actual) {
+            assert_eq!(*pointer, leaked.as_ptr());
+        }
+    }
+
+    #[test]
+    fn into_static_strs_large_input() {
+        let len = if cfg!(miri) { 100 } else { 100_000 };
+        let strings: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+        let expected = strings.clone();
+
+        let actual = into_static_strs(strings);
 
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn into_static_strs_releases_spare_capacity() {
+        let mut string = String::with_capacity(1000);
+        string.push_str("hi");
+        let strings = vec![string, String::new()];
+
+        let (actual, allocations) = count_allocations(|| into_static_strs(strings));
+
+        assert_eq!(vec!["hi", ""], actual);
+        // The output vector and the shrunk copy of the string.
+        assert_eq!(2, allocations);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_copies_strings() {
+        let strings = vec![
+            String::from("string_a"),
+            String::from("string_b"),
+            String::from("string_c"),
+        ];
+
+        let (actual, allocations) = count_allocations(|| vec_string_to_static_str(&strings));
+
+        assert_eq!(vec!["string_a", "string_b", "string_c"], actual);
+        assert!(allocations > strings.len());
+    }
+
+    #[test]
+    fn try_into_static_strs_from_dynamic_allocation() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = try_into_static_strs(strings);
+
+        assert_eq!(Ok(vec!["string_a", "string_b"]), actual);
+    }
+
+    #[test]
+    fn try_into_static_strs_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = try_into_static_strs(strings);
+
+        assert_eq!(Ok(Vec::<&'static str>::new()), actual);
+    }
+
+    #[test]
+    fn try_vec_with_capacity_reports_failure() {
+        let actual = try_vec_with_capacity::<&'static str>(usize::MAX);
+
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn intern_static_strs_shares_duplicates() {
+        let strings = vec![
+            "a".to_string(),
+            "a".to_string(),
+            "b".to_string(),
+            "a".to_string(),
+        ];
+
+        let actual = intern_static_strs(&strings);
+
+        assert_eq!(vec!["a", "a", "b", "a"], actual);
+        assert!(std::ptr::eq(actual[0], actual[1]));
+        assert!(std::ptr::eq(actual[0], actual[3]));
+        assert!(!std::ptr::eq(actual[0], actual[2]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn intern_static_strs_reported_counts_duplicates() {
+        let strings = vec![
+            "aa".to_string(),
+            "b".to_string(),
+            "aa".to_string(),
+            "你好".to_string(),
+            "aa".to_string(),
+            "你好".to_string(),
+        ];
+
+        let (actual, stats) = intern_static_strs_reported(&strings);
+
+        assert_eq!(strings, actual);
         assert_eq!(
-            vec![
-                "hello, world!",
-                "你好，世界！",
-                "こんにちは、世界！",
-                "안녕하세요, 세계!"
-            ],
-            actual
+            InternStats {
+                total: 6,
+                unique: 3,
+                bytes: 2 + 1 + 6,
+                bytes_saved: 2 + 2 + 6
+            },
+            stats
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn intern_static_strs_reported_no_duplicates() {
+        let strings = vec!["a".to_string(), "b".to_string()];
+
+        let (_, stats) = intern_static_strs_reported(&strings);
+
+        assert_eq!(
+            InternStats {
+                total: 2,
+                unique: 2,
+                bytes: 2,
+                bytes_saved: 0
+            },
+            stats
         );
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn intern_static_strs_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = intern_static_strs(&strings);
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+
+    #[test]
+    fn vec_string_as_strs_borrows_input() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = vec_string_as_strs(&strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+        assert!(std::ptr::eq(strings[0].as_str(), actual[0]));
+    }
+
+    #[test]
+    fn vec_string_as_strs_does_not_allocate_strings() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let (_, allocations) = count_allocations(|| vec_string_as_strs(&strings));
+
+        assert_eq!(1, allocations);
+    }
+
+    #[test]
+    fn static_strs_to_vec_string_round_trip() {
+        let strings = vec!["string_a".to_string(), String::new(), "日本語".to_string()];
+
+        let actual = static_strs_to_vec_string(&vec_string_to_static_str(&strings));
+
+        assert_eq!(strings, actual);
+    }
+
+    #[test]
+    fn static_strs_to_vec_string_empty_slice() {
+        let actual = static_strs_to_vec_string(&[]);
+
+        assert_eq!(Vec::<String>::new(), actual);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn unsafe_str_to_static_str_from_subslice() {
+        let string = String::from("hello world");
+
+        let actual = unsafe { unsafe_str_to_static_str(&string[0..5]) };
+
+        assert_eq!("hello", actual);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn unsafe_str_to_static_str_shares_source_bytes() {
+        let string = String::from("hello world");
+
+        let actual = unsafe { unsafe_str_to_static_str(&string[6..]) };
+
+        assert!(std::ptr::eq(&string[6..], actual));
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn unsafe_string_to_static_str_empty_string() {
+        let string = String::new();
+
+        let actual = unsafe { unsafe_string_to_static_str(&string) };
+
+        assert_eq!("", actual);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn unsafe_string_to_static_str_ascii() {
+        let string = String::from("string_a");
+
+        let actual = unsafe { unsafe_string_to_static_str(&string) };
+
+        assert_eq!("string_a", actual);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn unsafe_string_to_static_str_multibyte() {
+        let string = String::from("你好，世界！");
+
+        let actual = unsafe { unsafe_string_to_static_str(&string) };
+
+        assert_eq!("你好，世界！", actual);
+    }
+
     #[test]
     #[cfg(feature = "unsafe")]
     fn unsafe_vec_string_to_static_str_from_literals() {
         let strings = vec!["string_a".to_string(), "string_b".to_string()];
 
-        let actual = unsafe_vec_string_to_static_str(&strings);
+        let actual = unsafe { unsafe_vec_string_to_static_str(&strings) };
 
         assert_eq!(vec!["string_a", "string_b"], actual);
     }
@@ -147,7 +1542,7 @@ mod tests {
     fn unsafe_vec_string_to_static_str_from_dynamic_allocation() {
         let strings = vec![String::from("string_a"), String::from("string_b")];
 
-        let actual = unsafe_vec_string_to_static_str(&strings);
+        let actual = unsafe { unsafe_vec_string_to_static_str(&strings) };
 
         assert_eq!(vec!["string_a", "string_b"], actual);
     }
@@ -157,7 +1552,7 @@ mod tests {
     fn unsafe_vec_string_to_static_str_empty_vector() {
         let strings: Vec<String> = Vec::new();
 
-        let actual = unsafe_vec_string_to_static_str(&strings);
+        let actual = unsafe { unsafe_vec_string_to_static_str(&strings) };
 
         assert_eq!(Vec::<&'static str>::new(), actual);
     }
@@ -167,11 +1562,39 @@ mod tests {
     fn unsafe_vec_string_to_static_str_mixed_content() {
         let strings = vec!["".to_string(), "a".to_string(), "longer string".to_string()];
 
-        let actual = unsafe_vec_string_to_static_str(&strings);
+        let actual = unsafe { unsafe_vec_string_to_static_str(&strings) };
 
         assert_eq!(vec!["", "a", "longer string"], actual);
     }
 
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn unsafe_vec_string_to_static_str_subslice() {
+        let strings = [
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_c".to_string(),
+            "string_d".to_string(),
+        ];
+
+        let actual = unsafe { unsafe_vec_string_to_static_str(&strings[1..3]) };
+
+        assert_eq!(vec!["string_b", "string_c"], actual);
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe")]
+    fn unsafe_vec_string_to_static_str_allocates_output_once() {
+        let len = if cfg!(miri) { 100 } else { 100_000 };
+        let strings: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+
+        let (actual, allocations) =
+            count_allocations(|| unsafe { unsafe_vec_string_to_static_str(&strings) });
+
+        assert_eq!(strings, actual);
+        assert_eq!(1, allocations);
+    }
+
     #[test]
     #[cfg(feature = "unsafe")]
     fn unsafe_vec_string_to_static_str_special_characters() {
@@ -182,7 +1605,7 @@ mod tests {
             "안녕하세요, 세계!".to_string(),
         ];
 
-        let actual = unsafe_vec_string_to_static_str(&strings);
+        let actual = unsafe { unsafe_vec_string_to_static_str(&strings) };
 
         assert_eq!(
             vec![
@@ -0,0 +1,112 @@
+//! src/macros.rs
+
+/// Builds a `Vec<&'static str>` by leaking each expression.
+///
+/// Every argument may be any value implementing `AsRef<str>`, such as a
+/// string literal, a `String` or the result of `format!`. Each one is
+/// copied and leaked with [`str_to_static_str`](crate::str_to_static_str).
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::static_strs;
+///
+/// let name = String::from("world");
+/// let static_strs = static_strs!["hello", name, format!("{}!", 1 + 1)];
+/// assert_eq!(static_strs, vec!["hello", "world", "2!"]);
+/// ```
+#[macro_export]
+macro_rules! static_strs {
+    ($($s:expr),* $(,)?) => {{
+        let strs: $crate::__private::Vec<&'static str> = $crate::__private::vec![
+            $($crate::str_to_static_str(::core::convert::AsRef::<str>::as_ref(&$s))),*
+        ];
+        strs
+    }};
+}
+
+/// Formats a string like `format!` and leaks it as a `&'static str`.
+///
+/// This is shorthand for `Box::leak(format!(...).into_boxed_str())`: the
+/// formatted `String` is leaked in place, without another copy. It takes
+/// the same arguments as `format!`, including inline and positional
+/// arguments and format specifiers.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_format;
+///
+/// let id = 7;
+/// let label: &'static str = leak_format!("worker-{id:03}");
+/// assert_eq!(label, "worker-007");
+/// ```
+#[macro_export]
+macro_rules! leak_format {
+    ($($arg:tt)*) => {
+        $crate::__private::leak_string($crate::__private::format!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn static_strs_empty() {
+        let actual = static_strs![];
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+
+    #[test]
+    fn static_strs_from_literals() {
+        let actual = static_strs!["string_a", "string_b",];
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn static_strs_mixed_expressions() {
+        let string = String::from("string_b");
+        let x = 3;
+
+        let actual = static_strs!["string_a", string, format!("string_{x}")];
+
+        assert_eq!(vec!["string_a", "string_b", "string_3"], actual);
+    }
+
+    #[test]
+    fn static_strs_does_not_consume_variables() {
+        let string = String::from("string_a");
+
+        let actual = static_strs![string];
+
+        assert_eq!(vec![string.as_str()], actual);
+    }
+
+    #[test]
+    fn leak_format_with_specifiers() {
+        let name = "string";
+        let value = 1.5;
+
+        let actual: &'static str = leak_format!("{name}_{:>4}|{value:.2}|{:#x}", 7, 255);
+
+        assert_eq!("string_   7|1.50|0xff", actual);
+    }
+
+    #[test]
+    fn leak_format_outlives_arguments() {
+        let actual = {
+            let string = String::from("string_a");
+            leak_format!("{}-{}", string, string.len())
+        };
+
+        assert_eq!("string_a-8", actual);
+    }
+
+    #[test]
+    fn leak_format_literal_only() {
+        let actual = leak_format!("plain");
+
+        assert_eq!("plain", actual);
+    }
+}
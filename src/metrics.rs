@@ -0,0 +1,45 @@
+//! src/metrics.rs
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static LEAKED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LEAKED_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Records `count` leaked string slices totalling `bytes` bytes.
+pub(crate) fn record_leak(count: usize, bytes: usize) {
+    LEAKED_BYTES.fetch_add(bytes, Ordering::Relaxed);
+    LEAKED_COUNT.fetch_add(count, Ordering::Relaxed);
+}
+
+/// Returns the total number of string bytes leaked by this crate so far.
+///
+/// Every leaking function adds the byte length of each string it leaks.
+/// Memory reclaimed by a [`LeakGuard`](crate::LeakGuard) is never counted.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{leaked_bytes, str_to_static_str};
+///
+/// let before = leaked_bytes();
+/// let _ = str_to_static_str("hello");
+/// assert!(leaked_bytes() >= before + 5);
+/// ```
+pub fn leaked_bytes() -> usize {
+    LEAKED_BYTES.load(Ordering::Relaxed)
+}
+
+/// Returns the total number of string slices leaked by this crate so far.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{leaked_count, str_to_static_str};
+///
+/// let before = leaked_count();
+/// let _ = str_to_static_str("hello");
+/// assert!(leaked_count() >= before + 1);
+/// ```
+pub fn leaked_count() -> usize {
+    LEAKED_COUNT.load(Ordering::Relaxed)
+}
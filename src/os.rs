@@ -0,0 +1,135 @@
+//! src/os.rs
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use crate::leak_boxed;
+
+/// Converts a vector of `String`s into a vector of `&'static OsStr`.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// `OsStr` slices from copies of the input strings. Use with caution.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static OsStr` references to copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use std::ffi::OsStr;
+/// use vec_string_to_static_str::vec_string_to_static_osstr;
+///
+/// let strings = vec![String::from("--verbose")];
+/// let static_osstrs = vec_string_to_static_osstr(&strings);
+/// assert_eq!(static_osstrs, vec![OsStr::new("--verbose")]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_osstr(strings: &[String]) -> Vec<&'static OsStr> {
+    let mut osstrs: Vec<&'static OsStr> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        let boxed = OsString::from(string).into_boxed_os_str();
+        osstrs.push(leak_boxed(boxed, string.len()));
+    }
+
+    osstrs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static Path`.
+///
+/// This function leaks memory as it uses `Box::leak` to create static
+/// `Path` slices from copies of the input strings. Use with caution. Empty
+/// strings become empty paths.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static Path` references to copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use std::path::Path;
+/// use vec_string_to_static_str::vec_string_to_static_path;
+///
+/// let strings = vec![String::from("/usr/lib"), String::from("/opt/lib")];
+/// let static_paths = vec_string_to_static_path(&strings);
+/// assert_eq!(static_paths, vec![Path::new("/usr/lib"), Path::new("/opt/lib")]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_path(strings: &[String]) -> Vec<&'static Path> {
+    let mut paths: Vec<&'static Path> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        let boxed = PathBuf::from(string).into_boxed_path();
+        paths.push(leak_boxed(boxed, string.len()));
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_string_to_static_osstr_matches_originals() {
+        let strings = vec![
+            "string_a".to_string(),
+            "".to_string(),
+            "你好，世界！".to_string(),
+        ];
+
+        let actual = vec_string_to_static_osstr(&strings);
+
+        assert_eq!(strings.len(), actual.len());
+        for (string, osstr) in strings.iter().zip(&actual) {
+            assert_eq!(OsStr::new(string), *osstr);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_osstr_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = vec_string_to_static_osstr(&strings);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn vec_string_to_static_path_matches_originals() {
+        let strings = vec![
+            "/usr/lib".to_string(),
+            "relative/dir".to_string(),
+            "".to_string(),
+        ];
+
+        let actual = vec_string_to_static_path(&strings);
+
+        assert_eq!(strings.len(), actual.len());
+        for (string, path) in strings.iter().zip(&actual) {
+            assert_eq!(Path::new(string), *path);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_path_empty_string() {
+        let strings = vec![String::new()];
+
+        let actual = vec_string_to_static_path(&strings);
+
+        assert_eq!(Path::new(""), actual[0]);
+        assert!(actual[0].as_os_str().is_empty());
+    }
+}
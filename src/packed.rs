@@ -0,0 +1,316 @@
+//! src/packed.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Converts a vector of `String`s into a vector of `&'static str` backed by
+/// a single leaked allocation.
+///
+/// All input strings are copied back to back into one buffer, which is
+/// leaked once with `Box::leak`. The returned slices point into regions of
+/// that buffer, so converting many short strings costs one allocation
+/// instead of one per string.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references into the shared leaked buffer.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_packed;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = vec_string_to_static_str_packed(&strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_packed(strings: &[String]) -> Vec<&'static str> {
+    let (buffer, spans) = pack(strings);
+    let mut strs: Vec<&'static str> = Vec::with_capacity(spans.len());
+
+    for span in spans {
+        strs.push(&buffer[span]);
+    }
+
+    strs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str` backed by
+/// a single leaked allocation, along with the byte range of each string
+/// within that allocation.
+///
+/// This behaves like [`vec_string_to_static_str_packed`], but also returns
+/// the `start..end` span of every string. The shared buffer is the
+/// concatenation of all input strings, so the spans index into
+/// `strings.concat()`, which makes it possible to persist the flat buffer
+/// plus offsets and rebuild the table later.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references into the shared leaked buffer, and
+/// a vector with the byte range each of them occupies in that buffer.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_with_spans;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let (static_strs, spans) = vec_string_to_static_str_with_spans(&strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// assert_eq!(spans, vec![0..5, 5..10]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_with_spans(
+    strings: &[String],
+) -> (Vec<&'static str>, Vec<Range<usize>>) {
+    let (buffer, spans) = pack(strings);
+    let mut strs: Vec<&'static str> = Vec::with_capacity(spans.len());
+
+    for span in &spans {
+        strs.push(&buffer[span.clone()]);
+    }
+
+    (strs, spans)
+}
+
+/// Joins a vector of `String`s with a separator into a single leaked
+/// `&'static str`.
+///
+/// The joined string is built in one allocation and leaked once with
+/// `Box::leak`, which suits long-lived text such as a static help message.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be joined.
+/// * `sep` - The string slice placed between consecutive strings.
+///
+/// # Returns
+///
+/// A `&'static str` reference to the leaked joined string.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_join;
+///
+/// let lines = vec![String::from("usage: tool"), String::from("  -h  help")];
+/// let help: &'static str = leak_join(&lines, "\n");
+/// assert_eq!(help, "usage: tool\n  -h  help");
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_join(strings: &[String], sep: &str) -> &'static str {
+    crate::leak_str(strings.join(sep).into_boxed_str())
+}
+
+/// Copies every string into one buffer and leaks it, returning the buffer
+/// and the span each string occupies in it.
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+fn pack(strings: &[String]) -> (&'static str, Vec<Range<usize>>) {
+    // The strings are all in memory at once, so their combined length fits
+    // in a `usize`; the checked sum turns a violation of that invariant into
+    // a panic instead of a wrapped length and a buffer that is too small.
+    let total_len = checked_total_len(strings.iter().map(String::len))
+        .expect("the combined length of the strings overflows usize");
+    let mut buffer = String::with_capacity(total_len);
+    let mut spans: Vec<Range<usize>> = Vec::with_capacity(strings.len());
+
+    // Every span starts and ends between two whole input strings, so
+    // slicing the buffer can never split a UTF-8 character.
+    for string in strings {
+        let start = buffer.len();
+        buffer.push_str(string);
+        spans.push(start..buffer.len());
+    }
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_leak(strings.len(), total_len);
+    crate::Caller::here().record(total_len);
+
+    (crate::leak_unmetered(buffer.into_boxed_str()), spans)
+}
+
+/// Returns the sum of `lengths`, or `None` if it overflows a `usize`.
+fn checked_total_len(lengths: impl IntoIterator<Item = usize>) -> Option<usize> {
+    lengths.into_iter().try_fold(0usize, usize::checked_add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn vec_string_to_static_str_packed_from_dynamic_allocation() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = vec_string_to_static_str_packed(&strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_packed_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = vec_string_to_static_str_packed(&strings);
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_packed_mixed_content() {
+        let strings = vec!["".to_string(), "a".to_string(), "longer string".to_string()];
+
+        let actual = vec_string_to_static_str_packed(&strings);
+
+        assert_eq!(vec!["", "a", "longer string"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_packed_special_characters() {
+        let strings = vec![
+            "hello, world!".to_string(),
+            "你好，世界！".to_string(),
+            "こんにちは、世界！".to_string(),
+            "안녕하세요, 세계!".to_string(),
+        ];
+
+        let actual = vec_string_to_static_str_packed(&strings);
+
+        assert_eq!(
+            vec![
+                "hello, world!",
+                "你好，世界！",
+                "こんにちは、世界！",
+                "안녕하세요, 세계!"
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn vec_string_to_static_str_packed_single_allocation() {
+        // Miri is far slower than native execution, so it checks fewer strings.
+        let len = if cfg!(miri) { 100 } else { 10_000 };
+        let strings: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+        let total_len: usize = strings.iter().map(String::len).sum();
+
+        let actual = vec_string_to_static_str_packed(&strings);
+
+        assert_eq!(strings, actual);
+
+        let start = actual[0].as_ptr() as usize;
+        let end = start + total_len;
+        for pair in actual.windows(2) {
+            assert_eq!(
+                pair[0].as_ptr() as usize + pair[0].len(),
+                pair[1].as_ptr() as usize
+            );
+        }
+        let last = actual[actual.len() - 1];
+        assert_eq!(end, last.as_ptr() as usize + last.len());
+    }
+
+    #[test]
+    fn vec_string_to_static_str_with_spans_reconstructs_strings() {
+        let strings = vec![
+            "string_a".to_string(),
+            "".to_string(),
+            "你好，世界！".to_string(),
+            "b".to_string(),
+        ];
+        let buffer = strings.concat();
+
+        let (actual, spans) = vec_string_to_static_str_with_spans(&strings);
+
+        assert_eq!(strings, actual);
+        assert_eq!(strings.len(), spans.len());
+        for ((string, span), leaked) in strings.iter().zip(&spans).zip(&actual) {
+            assert_eq!(string, &buffer[span.clone()]);
+            assert_eq!(*leaked, &buffer[span.clone()]);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_with_spans_point_into_shared_buffer() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let (actual, spans) = vec_string_to_static_str_with_spans(&strings);
+
+        let base = actual[0].as_ptr() as usize;
+        for (leaked, span) in actual.iter().zip(&spans) {
+            assert_eq!(base + span.start, leaked.as_ptr() as usize);
+            assert_eq!(span.len(), leaked.len());
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_with_spans_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let (actual, spans) = vec_string_to_static_str_with_spans(&strings);
+
+        assert!(actual.is_empty());
+        assert!(spans.is_empty());
+    }
+
+    #[test]
+    fn leak_join_with_separator() {
+        let strings = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let actual: &'static str = leak_join(&strings, ", ");
+
+        assert_eq!("a, b, c", actual);
+    }
+
+    #[test]
+    fn leak_join_allocates_once() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let (actual, allocations) = count_allocations(|| leak_join(&strings, "-"));
+
+        assert_eq!("string_a-string_b", actual);
+        assert_eq!(1, allocations);
+    }
+
+    #[test]
+    fn leak_join_single_and_empty() {
+        let single = vec!["string_a".to_string()];
+        let empty: Vec<String> = Vec::new();
+
+        assert_eq!("string_a", leak_join(&single, ", "));
+        assert_eq!("", leak_join(&empty, ", "));
+    }
+
+    #[test]
+    fn checked_total_len_sums_lengths() {
+        let len = if cfg!(miri) { 100 } else { 10_000 };
+        let strings: Vec<String> = (0..len).map(|i| "é".repeat(i % 7)).collect();
+
+        let actual = checked_total_len(strings.iter().map(String::len));
+
+        assert_eq!(Some(strings.concat().len()), actual);
+        assert_eq!(Some(0), checked_total_len([]));
+    }
+
+    #[test]
+    fn checked_total_len_detects_overflow() {
+        assert_eq!(Some(usize::MAX), checked_total_len([usize::MAX - 1, 1]));
+        assert_eq!(None, checked_total_len([usize::MAX, 1]));
+        assert_eq!(None, checked_total_len([usize::MAX / 2 + 1; 2]));
+    }
+}
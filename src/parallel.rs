@@ -0,0 +1,80 @@
+//! src/parallel.rs
+
+use rayon::prelude::*;
+
+use crate::{leak_str_at, Caller};
+
+/// Converts a vector of `String`s into a vector of `&'static str` in parallel.
+///
+/// Each string is copied and leaked independently on rayon's thread pool,
+/// which speeds up conversion of very large inputs. The output preserves the
+/// order of the input.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::par_vec_string_to_static_str;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = par_vec_string_to_static_str(&strings);
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn par_vec_string_to_static_str(strings: &[String]) -> Vec<&'static str> {
+    // The strings are leaked on rayon's threads, out of reach of
+    // `#[track_caller]`, so the call site is captured here.
+    let caller = Caller::here();
+
+    strings
+        .par_iter()
+        .map(|string| leak_str_at(string.as_str().into(), caller))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec_string_to_static_str;
+
+    #[test]
+    fn par_vec_string_to_static_str_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = par_vec_string_to_static_str(&strings);
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+
+    #[test]
+    fn par_vec_string_to_static_str_matches_serial_version() {
+        // A small xorshift generator keeps the input varied but reproducible.
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        // Miri is far slower than native execution, so it checks fewer strings.
+        let len = if cfg!(miri) { 100 } else { 50_000 };
+        let strings: Vec<String> = (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                "é".repeat((state % 16) as usize) + &state.to_string()
+            })
+            .collect();
+
+        let expected = vec_string_to_static_str(&strings);
+        let actual = par_vec_string_to_static_str(&strings);
+
+        assert_eq!(expected.len(), actual.len());
+        for (expected, actual) in expected.iter().zip(&actual) {
+            assert_eq!(expected, actual);
+        }
+    }
+}
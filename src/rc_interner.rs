@@ -0,0 +1,421 @@
+//! src/rc_interner.rs
+
+use alloc::rc::{Rc, Weak};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+use hashbrown::HashTable;
+
+use crate::BudgetExceeded;
+
+/// A string interner that shares `Rc<str>`s instead of leaking.
+///
+/// Interning a string equal to one that is still alive returns a clone of
+/// the same `Rc<str>`, so equal strings share one allocation just like with
+/// [`Interner`](crate::Interner). The interner itself only holds `Weak`
+/// references, so a string stops being interned as soon as its last `Rc`
+/// is dropped: [`RcInterner::len`] and [`RcInterner::is_empty`] only count
+/// strings that are still alive, and interning it again allocates a new
+/// `Rc<str>`.
+///
+/// The cache entry of a dropped string, which keeps its allocation around
+/// through the `Weak`, is removed lazily: when its slot is reused by an
+/// insertion with the same hash, such as interning the same string again,
+/// or when [`RcInterner::purge`] removes it. `purge` also runs
+/// automatically before the cache has to grow, so dead entries never make
+/// it grow.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+/// use vec_string_to_static_str::RcInterner;
+///
+/// let mut interner = RcInterner::new();
+/// let first = interner.intern("hello");
+/// let second = interner.intern("hello");
+/// assert!(Rc::ptr_eq(&first, &second));
+///
+/// drop((first, second));
+/// assert!(interner.is_empty());
+/// ```
+#[derive(Default)]
+pub struct RcInterner {
+    strings: HashTable<(u64, Weak<str>)>,
+    hash_builder: RandomState,
+}
+
+impl RcInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self {
+            strings: HashTable::new(),
+            hash_builder: RandomState::new(),
+        }
+    }
+
+    /// Interns a string slice, allocating a new `Rc<str>` only if no equal
+    /// string is currently alive.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to be interned.
+    ///
+    /// # Returns
+    ///
+    /// An `Rc<str>` that shares its allocation with every other live `Rc`
+    /// returned for equal content.
+    pub fn intern(&mut self, s: &str) -> Rc<str> {
+        let hash = self.hash_builder.hash_one(s);
+        let eq = |(_, weak): &(u64, Weak<str>)| weak.upgrade().is_some_and(|rc| &*rc == s);
+
+        if let Some((_, weak)) = self.strings.find(hash, eq) {
+            if let Some(rc) = weak.upgrade() {
+                return rc;
+            }
+        }
+
+        let rc: Rc<str> = Rc::from(s);
+
+        // A dead entry with the same hash most likely belonged to an earlier
+        // copy of `s`, so take over its slot rather than adding a duplicate.
+        let dead =
+            |(entry_hash, weak): &(u64, Weak<str>)| *entry_hash == hash && weak.strong_count() == 0;
+        if let Some((_, weak)) = self.strings.find_mut(hash, dead) {
+            *weak = Rc::downgrade(&rc);
+            return rc;
+        }
+
+        if self.strings.len() == self.strings.capacity() {
+            // Reclaim the entries of freed strings before growing the table.
+            self.purge();
+        }
+
+        self.strings
+            .insert_unique(hash, (hash, Rc::downgrade(&rc)), |(hash, _)| *hash);
+
+        rc
+    }
+
+    /// Removes the cache entries of strings whose last `Rc` has been dropped,
+    /// releasing their allocations.
+    pub fn purge(&mut self) {
+        self.strings.retain(|(_, weak)| weak.strong_count() > 0);
+    }
+
+    /// Returns the number of interned strings that are still alive.
+    ///
+    /// The entries of dropped strings are skipped, so this walks the whole
+    /// cache.
+    pub fn len(&self) -> usize {
+        self.strings
+            .iter()
+            .filter(|(_, weak)| weak.strong_count() > 0)
+            .count()
+    }
+
+    /// Returns `true` if no interned string is still alive.
+    pub fn is_empty(&self) -> bool {
+        self.strings
+            .iter()
+            .all(|(_, weak)| weak.strong_count() == 0)
+    }
+}
+
+/// A budget-capped string interner that can free strings nobody else uses.
+///
+/// Leaked strings can never be freed while someone may still hold them, so
+/// this interner hands out `Rc<str>` rather than `&'static str`. Unlike
+/// [`RcInterner`], it keeps a strong reference to every string it interns,
+/// so strings stay cached even while no caller holds them.
+/// [`CompactingInterner::compact`] drops the entries only the interner
+/// still references, which frees their memory and returns their bytes to
+/// the budget.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::CompactingInterner;
+///
+/// let mut interner = CompactingInterner::with_budget(10);
+/// let hello = interner.intern("hello").unwrap();
+/// let world = interner.intern("world").unwrap();
+/// assert!(interner.intern("again").is_err());
+///
+/// drop(world);
+/// assert_eq!(interner.compact(), 1);
+/// assert!(interner.intern("again").is_ok());
+/// assert_eq!(&*hello, "hello");
+/// ```
+#[derive(Default)]
+pub struct CompactingInterner {
+    strings: HashTable<Rc<str>>,
+    hash_builder: RandomState,
+    bytes: usize,
+    budget: Option<usize>,
+}
+
+impl CompactingInterner {
+    /// Creates an empty interner without a budget.
+    pub fn new() -> Self {
+        Self {
+            strings: HashTable::new(),
+            hash_builder: RandomState::new(),
+            bytes: 0,
+            budget: None,
+        }
+    }
+
+    /// Creates an empty interner that holds at most `max_bytes` bytes of
+    /// strings at a time.
+    ///
+    /// Once the budget is used up, [`CompactingInterner::intern`]
+    /// returns an error for every string that is not interned yet, until
+    /// [`CompactingInterner::compact`] frees enough space.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_bytes` - The maximum number of string bytes the interner may
+    ///   hold at once.
+    pub fn with_budget(max_bytes: usize) -> Self {
+        Self {
+            budget: Some(max_bytes),
+            ..Self::new()
+        }
+    }
+
+    /// Interns a string slice, allocating a new `Rc<str>` only if no equal
+    /// string is cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string slice to be interned.
+    ///
+    /// # Returns
+    ///
+    /// An `Rc<str>` that shares its allocation with every other `Rc`
+    /// returned for equal content since it was cached, or a
+    /// [`BudgetExceeded`] error if `s` is not cached yet and caching it
+    /// would exceed the budget set with [`CompactingInterner::with_budget`].
+    /// An interner without a budget never returns an error.
+    pub fn intern(&mut self, s: &str) -> Result<Rc<str>, BudgetExceeded> {
+        let hash = self.hash_builder.hash_one(s);
+
+        if let Some(rc) = self.strings.find(hash, |rc| &**rc == s) {
+            return Ok(Rc::clone(rc));
+        }
+
+        if let Some(budget) = self.budget {
+            if self.bytes.saturating_add(s.len()) > budget {
+                return Err(BudgetExceeded {
+                    requested: s.len(),
+                    used: self.bytes,
+                    budget,
+                });
+            }
+        }
+
+        let rc: Rc<str> = Rc::from(s);
+        let hash_builder = &self.hash_builder;
+        self.strings
+            .insert_unique(hash, Rc::clone(&rc), |rc| hash_builder.hash_one(&**rc));
+        self.bytes += s.len();
+
+        Ok(rc)
+    }
+
+    /// Removes the strings that only the interner still references.
+    ///
+    /// Their memory is freed and their bytes no longer count against the
+    /// budget. Strings that a caller still holds an `Rc` to stay cached.
+    ///
+    /// # Returns
+    ///
+    /// The number of strings removed.
+    pub fn compact(&mut self) -> usize {
+        let before = self.strings.len();
+        let mut freed = 0;
+
+        self.strings.retain(|rc| {
+            let unused = Rc::strong_count(rc) == 1;
+            if unused {
+                freed += rc.len();
+            }
+            !unused
+        });
+        self.bytes -= freed;
+
+        before - self.strings.len()
+    }
+
+    /// Returns the number of string bytes the interner currently holds.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the number of cached strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings are cached.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc_interner_new_is_empty() {
+        let interner = RcInterner::new();
+
+        assert!(interner.is_empty());
+        assert_eq!(0, interner.len());
+    }
+
+    #[test]
+    fn rc_interner_intern_shares_storage() {
+        let mut interner = RcInterner::new();
+
+        let first = interner.intern("a");
+        let second = interner.intern("a");
+
+        assert_eq!("a", &*first);
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn rc_interner_drops_entry_with_last_clone() {
+        let mut interner = RcInterner::new();
+        let first = interner.intern("a");
+        let second = interner.intern("a");
+        assert!(Rc::ptr_eq(&first, &second));
+
+        drop(first);
+        assert_eq!(1, interner.len());
+        drop(second);
+
+        assert!(interner.is_empty());
+        assert_eq!(0, interner.len());
+    }
+
+    #[test]
+    fn rc_interner_purge_removes_dropped_strings() {
+        let mut interner = RcInterner::new();
+        let first = interner.intern("a");
+        let second = interner.intern("a");
+        let kept = interner.intern("b");
+
+        drop(first);
+        drop(second);
+        interner.purge();
+
+        assert_eq!(1, interner.strings.len());
+        assert!(Rc::ptr_eq(&kept, &interner.intern("b")));
+    }
+
+    #[test]
+    fn rc_interner_reinterns_dropped_string() {
+        let mut interner = RcInterner::new();
+        drop(interner.intern("a"));
+
+        let actual = interner.intern("a");
+
+        assert_eq!("a", &*actual);
+        assert_eq!(1, Rc::strong_count(&actual));
+        assert_eq!(1, interner.len());
+    }
+
+    #[test]
+    fn rc_interner_reinterning_reuses_dead_slot() {
+        let mut interner = RcInterner::new();
+        let kept = interner.intern("b");
+
+        for _ in 0..10 {
+            drop(interner.intern("a"));
+        }
+        let actual = interner.intern("a");
+
+        assert_eq!(2, interner.strings.len());
+        assert!(Rc::ptr_eq(&actual, &interner.intern("a")));
+        assert!(Rc::ptr_eq(&kept, &interner.intern("b")));
+    }
+
+    #[test]
+    fn rc_interner_purges_before_growing() {
+        let mut interner = RcInterner::new();
+
+        for i in 0..1000 {
+            drop(interner.intern(&format!("string_{i}")));
+        }
+
+        assert!(interner.strings.len() < 1000);
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn compacting_interner_intern_shares_storage() {
+        let mut interner = CompactingInterner::new();
+
+        let first = interner.intern("a").unwrap();
+        let second = interner.intern("a").unwrap();
+
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(1, interner.len());
+        assert_eq!(1, interner.bytes());
+    }
+
+    #[test]
+    fn compacting_interner_keeps_unheld_strings_until_compacted() {
+        let mut interner = CompactingInterner::new();
+        let first = interner.intern("a").unwrap();
+        let address = Rc::as_ptr(&first);
+        drop(first);
+
+        let actual = interner.intern("a").unwrap();
+
+        assert_eq!(address, Rc::as_ptr(&actual));
+    }
+
+    #[test]
+    fn compacting_interner_compact_removes_unreferenced() {
+        let mut interner = CompactingInterner::new();
+        let kept = interner.intern("string_a").unwrap();
+        let dropped = interner.intern("string_b").unwrap();
+        let dropped_clone = interner.intern("string_b").unwrap();
+        drop(interner.intern("string_c").unwrap());
+
+        drop(dropped);
+        drop(dropped_clone);
+        let removed = interner.compact();
+
+        assert_eq!(2, removed);
+        assert_eq!(1, interner.len());
+        assert_eq!(8, interner.bytes());
+        assert!(Rc::ptr_eq(&kept, &interner.intern("string_a").unwrap()));
+    }
+
+    #[test]
+    fn compacting_interner_compact_frees_budget() {
+        let mut interner = CompactingInterner::with_budget(2);
+        drop(interner.intern("a").unwrap());
+        let kept = interner.intern("b").unwrap();
+
+        assert_eq!(
+            Err(BudgetExceeded {
+                requested: 1,
+                used: 2,
+                budget: 2,
+            }),
+            interner.intern("c")
+        );
+
+        assert_eq!(1, interner.compact());
+        assert_eq!("c", &*interner.intern("c").unwrap());
+        assert_eq!("b", &*kept);
+    }
+}
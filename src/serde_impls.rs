@@ -0,0 +1,93 @@
+//! src/serde_impls.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::into_static_strs;
+
+/// A serializable table of `&'static str`.
+///
+/// Serializing writes the strings as a plain sequence. Deserializing takes
+/// ownership of the incoming strings and leaks each one with `Box::leak`,
+/// so a table can be cached to disk and loaded back as `&'static str`.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::StaticStrVec;
+///
+/// let table = StaticStrVec(vec!["hello", "world"]);
+/// let json = serde_json::to_string(&table).unwrap();
+/// assert_eq!(json, r#"["hello","world"]"#);
+///
+/// let loaded: StaticStrVec = serde_json::from_str(&json).unwrap();
+/// assert_eq!(loaded, table);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StaticStrVec(pub Vec<&'static str>);
+
+impl Serialize for StaticStrVec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StaticStrVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+
+        Ok(StaticStrVec(into_static_strs(strings)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_str_vec_serializes_as_sequence() {
+        let table = StaticStrVec(vec!["string_a", "string_b"]);
+
+        let actual = serde_json::to_string(&table).unwrap();
+
+        assert_eq!(r#"["string_a","string_b"]"#, actual);
+    }
+
+    #[test]
+    fn static_str_vec_round_trip() {
+        let table = StaticStrVec(vec!["string_a", "", "你好，世界！"]);
+
+        let json = serde_json::to_string(&table).unwrap();
+        let actual: StaticStrVec = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(table, actual);
+    }
+
+    #[test]
+    fn static_str_vec_deserializes_owned_data() {
+        let json = String::from(r#"["string_\"a\"","string_b"]"#);
+
+        let actual: StaticStrVec = serde_json::from_str(&json).unwrap();
+        drop(json);
+
+        assert_eq!(StaticStrVec(vec!["string_\"a\"", "string_b"]), actual);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn interner_dictionary_serde_round_trip() {
+        let mut interner = crate::Interner::new();
+        let _ = interner.intern("string_a");
+        let _ = interner.intern("string_b");
+
+        let json = serde_json::to_string(&interner.to_dictionary()).unwrap();
+        let words: Vec<String> = serde_json::from_str(&json).unwrap();
+        let restored = crate::Interner::from_dictionary(&words);
+
+        assert_eq!(2, restored.len());
+        assert!(restored.contains("string_a"));
+        assert!(restored.contains("string_b"));
+    }
+}
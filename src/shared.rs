@@ -0,0 +1,117 @@
+//! src/shared.rs
+
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+/// Converts a vector of `String`s into a vector of reference-counted
+/// `Arc<str>` without leaking.
+///
+/// This is the non-leaking alternative for callers that need cheap shared
+/// ownership rather than a true `'static` lifetime: each string is copied
+/// into an `Arc<str>`, which is freed once its last clone is dropped.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `Arc<str>` holding copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use vec_string_to_static_str::vec_string_to_arc_str;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let arcs = vec_string_to_arc_str(&strings);
+/// let shared = Arc::clone(&arcs[0]);
+/// assert_eq!(&*shared, "hello");
+/// ```
+pub fn vec_string_to_arc_str(strings: &[String]) -> Vec<Arc<str>> {
+    let mut arcs: Vec<Arc<str>> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        arcs.push(Arc::from(string.as_str()));
+    }
+
+    arcs
+}
+
+/// Converts a vector of `String`s into a vector of reference-counted
+/// `Rc<str>` without leaking.
+///
+/// This behaves like [`vec_string_to_arc_str`], but uses the cheaper,
+/// single-threaded `Rc`.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A vector of `Rc<str>` holding copies of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_rc_str;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let rcs = vec_string_to_rc_str(&strings);
+/// assert_eq!(&*rcs[1], "world");
+/// ```
+pub fn vec_string_to_rc_str(strings: &[String]) -> Vec<Rc<str>> {
+    let mut rcs: Vec<Rc<str>> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        rcs.push(Rc::from(string.as_str()));
+    }
+
+    rcs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_deallocations;
+
+    #[test]
+    fn vec_string_to_arc_str_clone_shares_content() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let actual = vec_string_to_arc_str(&strings);
+        let shared = Arc::clone(&actual[0]);
+
+        assert_eq!("string_a", &*shared);
+        assert_eq!(2, Arc::strong_count(&actual[0]));
+        assert_eq!(1, Arc::strong_count(&actual[1]));
+        assert!(Arc::ptr_eq(&actual[0], &shared));
+    }
+
+    #[test]
+    fn vec_string_to_arc_str_is_freed_when_dropped() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+        let actual = vec_string_to_arc_str(&strings);
+
+        let ((), deallocations) = count_deallocations(|| drop(actual));
+
+        // The two strings and the vector holding them.
+        assert_eq!(3, deallocations);
+    }
+
+    #[test]
+    fn vec_string_to_rc_str_clone_shares_content() {
+        let strings = vec!["string_a".to_string(), "你好".to_string()];
+
+        let actual = vec_string_to_rc_str(&strings);
+        let shared = Rc::clone(&actual[1]);
+        drop(strings);
+
+        assert_eq!("你好", &*shared);
+        assert_eq!(2, Rc::strong_count(&shared));
+    }
+}
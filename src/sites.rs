@@ -0,0 +1,66 @@
+//! src/sites.rs
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::panic::Location;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+static SITES: Mutex<BTreeMap<&'static Location<'static>, usize>> = Mutex::new(BTreeMap::new());
+
+fn sites() -> MutexGuard<'static, BTreeMap<&'static Location<'static>, usize>> {
+    SITES.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Adds `bytes` leaked bytes to the total of the call site `location`.
+pub(crate) fn record_site(location: &'static Location<'static>, bytes: usize) {
+    // Growing the registry allocates, which would throw off the unit tests
+    // that count the allocations of the leaking functions.
+    #[cfg(test)]
+    crate::test_alloc::uncounted(|| add_bytes(location, bytes));
+    #[cfg(not(test))]
+    add_bytes(location, bytes);
+}
+
+fn add_bytes(location: &'static Location<'static>, bytes: usize) {
+    let mut sites = sites();
+    let total = sites.entry(location).or_insert(0);
+    // Memory freed with `free_all_leaked` can be leaked again, so the totals
+    // are not bounded by the address space.
+    *total = total.saturating_add(bytes);
+}
+
+/// Returns every source location that leaked through this crate, with the
+/// total number of string bytes leaked there.
+///
+/// Leaking functions and methods are `#[track_caller]` when the
+/// `debug-leak-sites` feature is enabled, so every leak is reported under
+/// the line in your code that called into this crate, such as a call to
+/// [`vec_string_to_static_str`](crate::vec_string_to_static_str) or
+/// [`Interner::intern`](crate::Interner::intern). The strings yielded by a
+/// [`LeakIter`](crate::LeakIter) count towards the line that called
+/// [`leak_iter`](crate::leak_iter), and the chunks of a
+/// [`LeakArena`](crate::LeakArena) towards the call that allocated them.
+///
+/// # Returns
+///
+/// A vector of `(location, bytes)` pairs, sorted by location.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{leak_sites, str_to_static_str};
+///
+/// let _ = str_to_static_str("hello");
+/// let line = line!() - 1;
+///
+/// let sites = leak_sites();
+/// assert!(sites
+///     .iter()
+///     .any(|(location, bytes)| location.line() == line && *bytes == 5));
+/// ```
+pub fn leak_sites() -> Vec<(&'static Location<'static>, usize)> {
+    sites()
+        .iter()
+        .map(|(&location, &bytes)| (location, bytes))
+        .collect()
+}
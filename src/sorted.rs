@@ -0,0 +1,183 @@
+//! src/sorted.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{leak_unmetered, str_to_static_str, EMPTY};
+
+/// Converts a vector of `String`s into a sorted, deduplicated `'static`
+/// slice of `&'static str`.
+///
+/// The inputs are sorted and adjacent duplicates are removed before
+/// anything is leaked, so every distinct string is leaked exactly once. The
+/// resulting table is itself leaked, which makes it directly usable with
+/// `binary_search` from anywhere in the program. An empty input returns
+/// [`EMPTY`](crate::EMPTY) without leaking anything.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A `&'static [&'static str]` holding each distinct input string once, in
+/// ascending order.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_sorted_dedup;
+///
+/// let strings = vec![
+///     String::from("world"),
+///     String::from("hello"),
+///     String::from("world"),
+/// ];
+/// let table = leak_sorted_dedup(&strings);
+/// assert_eq!(table, ["hello", "world"]);
+/// assert_eq!(table.binary_search(&"world"), Ok(1));
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_sorted_dedup(strings: &[String]) -> &'static [&'static str] {
+    if strings.is_empty() {
+        return EMPTY;
+    }
+
+    let mut sorted: Vec<&str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        sorted.push(string);
+    }
+
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut strs: Vec<&'static str> = Vec::with_capacity(sorted.len());
+
+    for s in sorted {
+        strs.push(str_to_static_str(s));
+    }
+
+    leak_unmetered(strs.into_boxed_slice())
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, sorted
+/// with a custom comparator.
+///
+/// The inputs are sorted before anything is leaked, so a collation order
+/// such as a locale-aware comparison can be plugged in for display tables.
+/// The sort is stable: strings that compare equal keep their input order.
+/// Duplicates are kept and leaked separately.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+/// * `cmp` - The comparator that defines the output order.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the input strings,
+/// ordered by `cmp`.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::leak_sorted_by;
+///
+/// let strings = vec![String::from("bb"), String::from("a"), String::from("ccc")];
+/// let by_length = leak_sorted_by(&strings, |a, b| b.len().cmp(&a.len()));
+/// assert_eq!(by_length, vec!["ccc", "bb", "a"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn leak_sorted_by<F: Fn(&str, &str) -> Ordering>(
+    strings: &[String],
+    cmp: F,
+) -> Vec<&'static str> {
+    let mut sorted: Vec<&str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        sorted.push(string);
+    }
+
+    sorted.sort_by(|a, b| cmp(a, b));
+
+    let mut strs: Vec<&'static str> = Vec::with_capacity(sorted.len());
+
+    for s in sorted {
+        strs.push(str_to_static_str(s));
+    }
+
+    strs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn leak_sorted_dedup_unsorted_with_duplicates() {
+        let strings = vec![
+            "string_c".to_string(),
+            "string_a".to_string(),
+            "string_b".to_string(),
+            "string_a".to_string(),
+            "string_c".to_string(),
+        ];
+
+        let actual = leak_sorted_dedup(&strings);
+
+        assert_eq!(["string_a", "string_b", "string_c"], actual);
+        assert_eq!(Ok(1), actual.binary_search(&"string_b"));
+        assert!(actual.binary_search(&"string_d").is_err());
+    }
+
+    #[test]
+    fn leak_sorted_dedup_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = leak_sorted_dedup(&strings);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn leak_sorted_dedup_leaks_only_unique() {
+        let strings = vec!["string_a".to_string(); 100];
+
+        let (actual, allocations) = count_allocations(|| leak_sorted_dedup(&strings));
+
+        assert_eq!(["string_a"], actual);
+        // The scratch vector, the single leaked string, the output vector.
+        assert_eq!(3, allocations);
+    }
+
+    #[test]
+    fn leak_sorted_by_case_insensitive() {
+        let strings = vec![
+            "banana".to_string(),
+            "Apple".to_string(),
+            "cherry".to_string(),
+            "apple".to_string(),
+            "Banana".to_string(),
+        ];
+
+        let actual = leak_sorted_by(&strings, |a, b| {
+            a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+        });
+
+        assert_eq!(vec!["Apple", "apple", "banana", "Banana", "cherry"], actual);
+    }
+
+    #[test]
+    fn leak_sorted_by_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = leak_sorted_by(&strings, str::cmp);
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+}
@@ -0,0 +1,82 @@
+//! src/split.rs
+
+use alloc::vec::Vec;
+
+use crate::str_to_static_str;
+
+/// Splits a string slice on a delimiter into a vector of `&'static str`.
+///
+/// Every token is copied and leaked with `Box::leak`. Splitting follows
+/// [`str::split`], so empty tokens between consecutive delimiters, or at
+/// either end of `s`, are preserved.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be split.
+/// * `delimiter` - The character separating tokens.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the tokens.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::split_to_static_strs;
+///
+/// let static_strs = split_to_static_strs("key=value", '=');
+/// assert_eq!(static_strs, vec!["key", "value"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn split_to_static_strs(s: &str, delimiter: char) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::new();
+
+    for token in s.split(delimiter) {
+        strs.push(str_to_static_str(token));
+    }
+
+    strs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_to_static_strs_preserves_empty_tokens() {
+        let actual = split_to_static_strs("a,b,,c", ',');
+
+        assert_eq!(vec!["a", "b", "", "c"], actual);
+    }
+
+    #[test]
+    fn split_to_static_strs_leading_and_trailing_delimiters() {
+        let actual = split_to_static_strs(",a,", ',');
+
+        assert_eq!(vec!["", "a", ""], actual);
+    }
+
+    #[test]
+    fn split_to_static_strs_empty_string() {
+        let actual = split_to_static_strs("", ',');
+
+        assert_eq!(vec![""], actual);
+    }
+
+    #[test]
+    fn split_to_static_strs_multibyte_delimiter() {
+        let actual = split_to_static_strs("你好，世界", '，');
+
+        assert_eq!(vec!["你好", "世界"], actual);
+    }
+
+    #[test]
+    fn split_to_static_strs_matches_str_split() {
+        let s = "string_a string_b  string_c";
+
+        let actual = split_to_static_strs(s, ' ');
+
+        assert_eq!(s.split(' ').collect::<Vec<_>>(), actual);
+    }
+}
@@ -0,0 +1,145 @@
+//! src/static_strs.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{into_static_strs, vec_string_to_static_str};
+
+/// A vector of `&'static str` that can be built with `.into()`.
+///
+/// Converting from a `Vec<String>` consumes it and leaks each string in
+/// place, like [`into_static_strs`]. Converting from a `&[String]` copies
+/// and leaks each string, like [`vec_string_to_static_str`]. The result
+/// dereferences to a slice, so it can be indexed and iterated directly.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::StaticStrs;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs: StaticStrs = strings.into();
+/// assert_eq!(static_strs[0], "hello");
+/// assert_eq!(static_strs.len(), 2);
+/// ```
+///
+/// Its `Debug` output lists at most the first few strings, followed by
+/// `...` when there are more, so a huge table does not flood logs.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct StaticStrs(pub Vec<&'static str>);
+
+/// The number of strings `Debug` output shows before truncating.
+pub(crate) const DEBUG_ENTRIES: usize = 8;
+
+/// Formats the items of an iterator as a `Debug` list, listing only the
+/// first [`DEBUG_ENTRIES`] and ending with `...` if there are more.
+pub(crate) struct Truncated<I>(pub(crate) I);
+
+impl<I> fmt::Debug for Truncated<I>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut items = self.0.clone();
+        let mut list = f.debug_list();
+        list.entries(items.by_ref().take(DEBUG_ENTRIES));
+        if items.next().is_some() {
+            list.entry(&format_args!("..."));
+        }
+        list.finish()
+    }
+}
+
+impl fmt::Debug for StaticStrs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StaticStrs")
+            .field(&Truncated(self.0.iter()))
+            .finish()
+    }
+}
+
+impl From<Vec<String>> for StaticStrs {
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    fn from(strings: Vec<String>) -> Self {
+        StaticStrs(into_static_strs(strings))
+    }
+}
+
+impl From<&[String]> for StaticStrs {
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    fn from(strings: &[String]) -> Self {
+        StaticStrs(vec_string_to_static_str(strings))
+    }
+}
+
+impl Deref for StaticStrs {
+    type Target = [&'static str];
+
+    fn deref(&self) -> &[&'static str] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_strs_from_vec_string() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let actual: StaticStrs = strings.into();
+
+        assert_eq!(StaticStrs(vec!["string_a", "string_b"]), actual);
+    }
+
+    #[test]
+    fn static_strs_from_slice() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let actual = StaticStrs::from(strings.as_slice());
+        drop(strings);
+
+        assert_eq!(StaticStrs(vec!["string_a", "string_b"]), actual);
+    }
+
+    #[test]
+    fn static_strs_deref_to_slice() {
+        let actual: StaticStrs = vec!["string_a".to_string(), "string_b".to_string()].into();
+
+        assert_eq!(["string_a", "string_b"], *actual);
+        assert_eq!(2, actual.len());
+        assert_eq!(Some(&"string_b"), actual.last());
+        assert!(actual.contains(&"string_a"));
+    }
+
+    #[test]
+    fn static_strs_from_empty_vector() {
+        let actual: StaticStrs = Vec::<String>::new().into();
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn static_strs_debug_lists_strings() {
+        let static_strs = StaticStrs(vec!["string_a", "string_b"]);
+
+        let actual = format!("{static_strs:?}");
+
+        assert_eq!(r#"StaticStrs(["string_a", "string_b"])"#, actual);
+    }
+
+    #[test]
+    fn static_strs_debug_truncates_large_inputs() {
+        let static_strs = StaticStrs(vec!["string_a"; 1000]);
+
+        let actual = format!("{static_strs:?}");
+
+        assert!(actual.starts_with(r#"StaticStrs(["string_a", "string_a""#));
+        assert!(actual.ends_with(r#""string_a", ...])"#));
+        assert_eq!(DEBUG_ENTRIES, actual.matches("string_a").count());
+    }
+}
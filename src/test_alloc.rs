@@ -0,0 +1,59 @@
+//! src/test_alloc.rs
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static DEALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = DEALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns its result together with the number of
+/// allocations it made on the current thread.
+pub(crate) fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let after = ALLOCATIONS.with(Cell::get);
+
+    (result, after - before)
+}
+
+/// Runs `f` and returns its result together with the number of
+/// deallocations it made on the current thread.
+pub(crate) fn count_deallocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = DEALLOCATIONS.with(Cell::get);
+    let result = f();
+    let after = DEALLOCATIONS.with(Cell::get);
+
+    (result, after - before)
+}
+
+/// Runs `f` without counting the allocations and deallocations it makes,
+/// for bookkeeping that the counting tests should not observe.
+#[cfg(any(feature = "cleanup", feature = "debug-leak-sites"))]
+pub(crate) fn uncounted<T>(f: impl FnOnce() -> T) -> T {
+    let allocations = ALLOCATIONS.with(Cell::get);
+    let deallocations = DEALLOCATIONS.with(Cell::get);
+    let result = f();
+    ALLOCATIONS.with(|count| count.set(allocations));
+    DEALLOCATIONS.with(|count| count.set(deallocations));
+
+    result
+}
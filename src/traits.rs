@@ -0,0 +1,86 @@
+//! src/traits.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::leak_str;
+
+/// Extension trait for converting collections of owned `String`s into a
+/// `Vec<&'static str>` with method-call syntax.
+///
+/// This is implemented for every `IntoIterator<Item = String>`, so it works
+/// on vectors as well as directly on iterator pipelines. Each string is
+/// leaked in place without being copied, like
+/// [`into_static_strs`](crate::into_static_strs).
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::IntoStaticStrVec;
+///
+/// let static_strs = ["hello", "world"].map(String::from).into_static_strs();
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+pub trait IntoStaticStrVec {
+    /// Consumes `self`, leaking each string into a `&'static str`.
+    #[must_use = "this leaks memory; discarding the result wastes it permanently"]
+    fn into_static_strs(self) -> Vec<&'static str>;
+}
+
+impl<I> IntoStaticStrVec for I
+where
+    I: IntoIterator<Item = String>,
+{
+    #[cfg_attr(feature = "debug-leak-sites", track_caller)]
+    fn into_static_strs(self) -> Vec<&'static str> {
+        let mut strs: Vec<&'static str> = Vec::new();
+
+        for string in self {
+            strs.push(leak_str(string.into_boxed_str()));
+        }
+
+        strs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_static_strs_on_vec() {
+        let strings = vec![String::from("string_a"), String::from("string_b")];
+
+        let actual = strings.into_static_strs();
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn into_static_strs_on_collected_iterator() {
+        let strings: Vec<String> = ["string_a", "string_b"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let actual = strings.into_static_strs();
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn into_static_strs_on_iterator() {
+        let actual = (0..3).map(|i| format!("string_{i}")).into_static_strs();
+
+        assert_eq!(vec!["string_0", "string_1", "string_2"], actual);
+    }
+
+    #[test]
+    fn into_static_strs_on_empty_vec() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = strings.into_static_strs();
+
+        assert_eq!(Vec::<&'static str>::new(), actual);
+    }
+}
@@ -0,0 +1,403 @@
+//! src/transform.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{leak_str, str_to_static_str};
+
+/// Converts a vector of `String`s into a vector of `&'static str`, with
+/// `prefix` prepended to every string.
+///
+/// Each prefixed string is built directly in its final allocation and
+/// leaked with `Box::leak`, so no intermediate `String`s are needed. An
+/// empty prefix leaves the strings unchanged.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+/// * `prefix` - The string slice to prepend to each string.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to leaked `prefix + string`
+/// concatenations.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_prefixed;
+///
+/// let routes = vec![String::from("users"), String::from("orders")];
+/// let static_strs = vec_string_to_static_str_prefixed(&routes, "/api/");
+/// assert_eq!(static_strs, vec!["/api/users", "/api/orders"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_prefixed(strings: &[String], prefix: &str) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(concat_to_static_str(prefix, string));
+    }
+
+    strs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, with
+/// `suffix` appended to every string.
+///
+/// This behaves like [`vec_string_to_static_str_prefixed`], but adds the
+/// given string slice at the end. An empty suffix leaves the strings
+/// unchanged.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+/// * `suffix` - The string slice to append to each string.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to leaked `string + suffix`
+/// concatenations.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_suffixed;
+///
+/// let names = vec![String::from("config"), String::from("cache")];
+/// let static_strs = vec_string_to_static_str_suffixed(&names, ".toml");
+/// assert_eq!(static_strs, vec!["config.toml", "cache.toml"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_suffixed(strings: &[String], suffix: &str) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(concat_to_static_str(string, suffix));
+    }
+
+    strs
+}
+
+/// Converts the `String`s for which `keep` returns `true` into a vector of
+/// `&'static str`.
+///
+/// The predicate is checked before anything is copied, so rejected strings
+/// are never leaked, and the output vector is sized for the kept strings
+/// only. The order of the kept strings is preserved.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be filtered and converted.
+/// * `keep` - A predicate deciding which strings to leak.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to copies of the kept strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_filtered;
+///
+/// let strings = vec![String::from(""), String::from("a"), String::from("b")];
+/// let static_strs = vec_string_to_static_str_filtered(&strings, |s| !s.is_empty());
+/// assert_eq!(static_strs, vec!["a", "b"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_filtered<F: Fn(&str) -> bool>(
+    strings: &[String],
+    keep: F,
+) -> Vec<&'static str> {
+    // Most strings may be rejected, so let the output grow with the kept
+    // strings instead of reserving room for all of them.
+    let mut strs: Vec<&'static str> = Vec::new();
+
+    for string in strings {
+        if keep(string) {
+            strs.push(str_to_static_str(string));
+        }
+    }
+
+    strs
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, applying
+/// `f` to every string first.
+///
+/// The `String` returned by `f` is leaked as is, without another copy, so
+/// transforms such as uppercasing, trimming or templating cost a single
+/// allocation per string.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be transformed and converted.
+/// * `f` - The transform applied to each string before it is leaked.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the leaked transformed strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_mapped;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = vec_string_to_static_str_mapped(&strings, str::to_uppercase);
+/// assert_eq!(static_strs, vec!["HELLO", "WORLD"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_mapped<F: Fn(&str) -> String>(
+    strings: &[String],
+    f: F,
+) -> Vec<&'static str> {
+    let mut strs: Vec<&'static str> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        strs.push(leak_str(f(string).into_boxed_str()));
+    }
+
+    strs
+}
+
+/// Converts a vector of `String`s into a vector of ASCII-lowercased
+/// `&'static str`.
+///
+/// Only ASCII letters are lowercased, with [`str::to_ascii_lowercase`];
+/// every other byte, including all non-ASCII characters, is copied
+/// unchanged. This makes the results suitable as keys of case-normalized
+/// lookup tables without a Unicode dependency.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be lowercased and converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to leaked lowercased copies of the
+/// input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_lowercase;
+///
+/// let strings = vec![String::from("HELLO"), String::from("MiXeD")];
+/// let static_strs = vec_string_to_static_str_lowercase(&strings);
+/// assert_eq!(static_strs, vec!["hello", "mixed"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_lowercase(strings: &[String]) -> Vec<&'static str> {
+    vec_string_to_static_str_mapped(strings, str::to_ascii_lowercase)
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str` with
+/// control characters escaped.
+///
+/// Each string is escaped with [`str::escape_debug`], so newlines, tabs and
+/// other non-printable characters become escape sequences such as `\n`,
+/// and the results are safe to print on a single line. Quotes and
+/// backslashes are escaped too, while printable non-ASCII characters are
+/// kept as is.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be escaped and converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to leaked escaped copies of the
+/// input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_escaped;
+///
+/// let strings = vec![String::from("line 1\nline 2")];
+/// let static_strs = vec_string_to_static_str_escaped(&strings);
+/// assert_eq!(static_strs, vec![r"line 1\nline 2"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_escaped(strings: &[String]) -> Vec<&'static str> {
+    vec_string_to_static_str_mapped(strings, |s| s.escape_debug().collect())
+}
+
+/// Leaks the concatenation of `first` and `second`, allocating only once.
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+fn concat_to_static_str(first: &str, second: &str) -> &'static str {
+    if first.is_empty() {
+        return str_to_static_str(second);
+    }
+    if second.is_empty() {
+        return str_to_static_str(first);
+    }
+
+    // Both halves are in memory, so their combined length fits in a `usize`.
+    let len = first
+        .len()
+        .checked_add(second.len())
+        .expect("the concatenated length overflows usize");
+    let mut concatenated = String::with_capacity(len);
+    concatenated.push_str(first);
+    concatenated.push_str(second);
+
+    leak_str(concatenated.into_boxed_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_allocations;
+
+    #[test]
+    fn vec_string_to_static_str_prefixed_adds_prefix() {
+        let strings = vec!["users".to_string(), "".to_string(), "你好".to_string()];
+
+        let actual = vec_string_to_static_str_prefixed(&strings, "/api/");
+
+        for (string, prefixed) in strings.iter().zip(&actual) {
+            assert!(prefixed.starts_with("/api/"));
+            assert_eq!(format!("/api/{string}"), *prefixed);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_prefixed_empty_prefix() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let actual = vec_string_to_static_str_prefixed(&strings, "");
+
+        assert_eq!(strings, actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_prefixed_allocates_once_per_string() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let (_, allocations) =
+            count_allocations(|| vec_string_to_static_str_prefixed(&strings, "prefix_"));
+
+        // The output vector and one allocation per string.
+        assert_eq!(3, allocations);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_filtered_drops_empty_strings() {
+        let strings = vec![
+            "".to_string(),
+            "a".to_string(),
+            "".to_string(),
+            "b".to_string(),
+        ];
+
+        let (actual, allocations) =
+            count_allocations(|| vec_string_to_static_str_filtered(&strings, |s| !s.is_empty()));
+
+        assert_eq!(vec!["a", "b"], actual);
+        // The output vector and one allocation per kept string.
+        assert_eq!(3, allocations);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_filtered_keeps_order() {
+        let strings: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+
+        let actual =
+            vec_string_to_static_str_filtered(&strings, |s| s.parse::<u32>().unwrap() % 2 == 0);
+
+        assert_eq!(vec!["0", "2", "4", "6", "8"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_filtered_sizes_output_for_kept_strings() {
+        let strings: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+
+        let actual = vec_string_to_static_str_filtered(&strings, |s| s == "500");
+
+        assert_eq!(vec!["500"], actual);
+        assert!(actual.capacity() < strings.len());
+    }
+
+    #[test]
+    fn vec_string_to_static_str_mapped_uppercases() {
+        let strings = vec!["string_a".to_string(), "".to_string(), "straße".to_string()];
+
+        let actual = vec_string_to_static_str_mapped(&strings, str::to_uppercase);
+
+        assert_eq!(vec!["STRING_A", "", "STRASSE"], actual);
+        for s in &actual {
+            assert_eq!(s.to_uppercase(), *s);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_mapped_trims() {
+        let strings = vec!["  string_a ".to_string(), "\tstring_b\n".to_string()];
+
+        let actual = vec_string_to_static_str_mapped(&strings, |s| s.trim().to_string());
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_lowercase_ascii_only() {
+        let strings = vec![
+            "HELLO".to_string(),
+            "MiXeD".to_string(),
+            "ÄÖÜ Straße".to_string(),
+        ];
+
+        let actual = vec_string_to_static_str_lowercase(&strings);
+
+        assert_eq!(vec!["hello", "mixed", "ÄÖÜ straße"], actual);
+        for s in &actual {
+            assert!(!s.bytes().any(|byte| byte.is_ascii_uppercase()));
+        }
+        assert_eq!(strings[2].len(), actual[2].len());
+    }
+
+    #[test]
+    fn vec_string_to_static_str_escaped_escapes_control_characters() {
+        let strings = vec![
+            "a\nb\tc".to_string(),
+            "\u{7}\r".to_string(),
+            "你好".to_string(),
+        ];
+
+        let actual = vec_string_to_static_str_escaped(&strings);
+
+        assert_eq!(vec![r"a\nb\tc", r"\u{7}\r", "你好"], actual);
+        for s in &actual {
+            assert!(!s.chars().any(char::is_control));
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_suffixed_adds_suffix() {
+        let strings = vec!["config".to_string(), "".to_string(), "你好".to_string()];
+
+        let actual = vec_string_to_static_str_suffixed(&strings, ".toml");
+
+        for (string, suffixed) in strings.iter().zip(&actual) {
+            assert!(suffixed.ends_with(".toml"));
+            assert_eq!(format!("{string}.toml"), *suffixed);
+        }
+    }
+
+    #[test]
+    fn vec_string_to_static_str_suffixed_empty_suffix() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let actual = vec_string_to_static_str_suffixed(&strings, "");
+
+        assert_eq!(strings, actual);
+    }
+}
@@ -0,0 +1,90 @@
+//! src/unicode.rs
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::intern_static_strs;
+
+/// Converts a vector of `String`s into a vector of NFC-normalized
+/// `&'static str`, leaking each distinct normalized value only once.
+///
+/// Every string is normalized to Unicode Normalization Form C before it is
+/// leaked, so strings that render identically but use different code point
+/// sequences, such as a precomposed `"é"` and `"e"` followed by a combining
+/// acute accent, become byte-identical. The normalized strings are then
+/// interned like [`intern_static_strs`], so such duplicates share one
+/// leaked allocation.
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be normalized and converted.
+///
+/// # Returns
+///
+/// A vector of `&'static str` references to the normalized strings, where
+/// strings with equal normal forms point to the same leaked allocation.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_nfc;
+///
+/// let strings = vec![String::from("caf\u{e9}"), String::from("cafe\u{301}")];
+/// let static_strs = vec_string_to_static_str_nfc(&strings);
+/// assert_eq!(static_strs, vec!["caf\u{e9}", "caf\u{e9}"]);
+/// assert!(std::ptr::eq(static_strs[0], static_strs[1]));
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_nfc(strings: &[String]) -> Vec<&'static str> {
+    let mut normalized: Vec<String> = Vec::with_capacity(strings.len());
+
+    for string in strings {
+        normalized.push(string.nfc().collect());
+    }
+
+    intern_static_strs(&normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_string_to_static_str_nfc_composes_decomposed_forms() {
+        let strings = vec!["\u{e9}".to_string(), "e\u{301}".to_string()];
+
+        let actual = vec_string_to_static_str_nfc(&strings);
+
+        assert_eq!(vec!["\u{e9}", "\u{e9}"], actual);
+        assert!(std::ptr::eq(actual[0], actual[1]));
+    }
+
+    #[test]
+    fn vec_string_to_static_str_nfc_keeps_distinct_strings() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+        let actual = vec_string_to_static_str_nfc(&strings);
+
+        assert_eq!(vec!["string_a", "string_b"], actual);
+    }
+
+    #[test]
+    fn vec_string_to_static_str_nfc_reorders_combining_marks() {
+        // A dot below and a dot above, in both orders, on the letter q.
+        let strings = vec!["q\u{307}\u{323}".to_string(), "q\u{323}\u{307}".to_string()];
+
+        let actual = vec_string_to_static_str_nfc(&strings);
+
+        assert_eq!("q\u{323}\u{307}", actual[0]);
+        assert!(std::ptr::eq(actual[0], actual[1]));
+    }
+
+    #[test]
+    fn vec_string_to_static_str_nfc_empty_vector() {
+        let strings: Vec<String> = Vec::new();
+
+        let actual = vec_string_to_static_str_nfc(&strings);
+
+        assert!(actual.is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+//! src/unleak.rs
+
+use alloc::vec::Vec;
+
+/// Frees a string slice that was previously leaked by this crate.
+///
+/// This is meant for test harnesses and leak-sensitive tooling that want
+/// to hand memory back after a conversion is no longer needed. The string
+/// is looked up in the registry kept by the `cleanup` feature and freed
+/// through the pointer that was recorded when it was leaked, so `s` itself
+/// only needs to locate it. A `&'static str` coerces to `*const str`, so
+/// leaked strings can be passed as they are.
+///
+/// # Arguments
+///
+/// * `s` - A pointer to a string slice returned by one of this crate's
+///   `Box::leak` based functions, such as
+///   [`str_to_static_str`](crate::str_to_static_str),
+///   [`vec_string_to_static_str`](crate::vec_string_to_static_str) or
+///   [`into_static_strs`](crate::into_static_strs).
+///
+/// # Panics
+///
+/// Panics if `s` is not empty and does not span a whole allocation that
+/// this crate leaked and has not freed yet. This rejects, among others:
+///
+/// - string literals or slices borrowed from other data,
+/// - strings from [`vec_string_to_static_str_packed`](crate::vec_string_to_static_str_packed)
+///   or [`LeakArena`](crate::LeakArena), which share an allocation,
+/// - subslices of a leaked string,
+/// - strings that were already unleaked or freed by
+///   [`free_all_leaked`](crate::free_all_leaked).
+///
+/// Empty strings own no allocation, so unleaking them does nothing.
+///
+/// # Safety
+///
+/// Neither `s` nor any copy of it may be used after this call. In
+/// particular, `s` must not be a string that this crate may hand out
+/// again, such as one returned by an [`Interner`](crate::Interner) or
+/// `global_intern`. `leaked_bytes` and `leaked_count` are not decreased.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{str_to_static_str, unleak};
+///
+/// let leaked = str_to_static_str("hello");
+/// assert_eq!(leaked, "hello");
+/// // SAFETY: `leaked` is not used again.
+/// unsafe { unleak(leaked) };
+/// ```
+pub unsafe fn unleak(s: *const str) {
+    let bytes = s as *const [u8];
+    if bytes.is_empty() {
+        return;
+    }
+
+    // SAFETY: the caller guarantees that the string is no longer used.
+    let freed = unsafe { crate::cleanup::free(bytes.cast(), bytes.len()) };
+    assert!(freed, "the string was not leaked whole by this crate");
+}
+
+/// Frees every string slice in a vector previously leaked by this crate.
+///
+/// # Arguments
+///
+/// * `strs` - A vector of string slices returned by one of this crate's
+///   `Box::leak` based functions.
+///
+/// # Panics
+///
+/// Panics if any element would make [`unleak`] panic. The elements before
+/// it are freed, and the rest are left alone.
+///
+/// # Safety
+///
+/// Every element of `strs` must satisfy the requirements of [`unleak`].
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::{unleak_all, vec_string_to_static_str};
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = vec_string_to_static_str(&strings);
+/// // SAFETY: none of the elements is used again.
+/// unsafe { unleak_all(static_strs) };
+/// ```
+pub unsafe fn unleak_all(strs: Vec<&'static str>) {
+    for s in strs {
+        // SAFETY: the caller guarantees that every element satisfies the
+        // requirements of `unleak`.
+        unsafe { unleak(s) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_alloc::count_deallocations;
+    use crate::{
+        into_static_strs, str_to_static_str, vec_string_to_static_str,
+        vec_string_to_static_str_packed,
+    };
+
+    #[test]
+    fn unleak_frees_leaked_string() {
+        // A reference captured by the closure would still be live, and
+        // protected as part of its argument, while the string is freed.
+        let leaked: *const str = str_to_static_str("string_a");
+
+        // SAFETY: `leaked` is not used again.
+        let ((), deallocations) = count_deallocations(|| unsafe { unleak(leaked) });
+
+        assert_eq!(1, deallocations);
+    }
+
+    #[test]
+    fn unleak_empty_string() {
+        let leaked = str_to_static_str("");
+
+        // SAFETY: `leaked` is not used again.
+        unsafe { unleak(leaked) };
+    }
+
+    #[test]
+    #[should_panic(expected = "not leaked whole by this crate")]
+    fn unleak_rejects_string_literal() {
+        // SAFETY: the literal is never freed, since `unleak` panics.
+        unsafe { unleak("string_a") };
+    }
+
+    #[test]
+    #[should_panic(expected = "not leaked whole by this crate")]
+    fn unleak_rejects_packed_string() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+        let leaked = vec_string_to_static_str_packed(&strings);
+
+        // SAFETY: the packed buffer is never freed, since `unleak` panics.
+        unsafe { unleak(leaked[0]) };
+    }
+
+    #[test]
+    fn unleak_all_frees_every_string() {
+        let strings = vec!["string_a".to_string(), "string_b".to_string()];
+        let leaked = vec_string_to_static_str(&strings);
+
+        // SAFETY: none of the elements is used again.
+        let ((), deallocations) = count_deallocations(|| unsafe { unleak_all(leaked) });
+
+        // The two strings and the vector holding them.
+        assert_eq!(3, deallocations);
+    }
+
+    #[test]
+    fn unleak_all_from_into_static_strs() {
+        let mut string = String::with_capacity(64);
+        string.push_str("string_a");
+        let leaked = into_static_strs(vec![string]);
+
+        // SAFETY: `into_static_strs` leaks every string as an exact-size
+        // `Box<str>`, and none of them is used again.
+        unsafe { unleak_all(leaked) };
+    }
+}
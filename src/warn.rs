@@ -0,0 +1,198 @@
+//! src/warn.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ops::Deref;
+
+use crate::vec_string_to_static_str;
+
+/// Wraps a leaked result and warns if it is dropped without being read.
+///
+/// `#[must_use]` only catches results that are discarded at the call site.
+/// A `WarnOnDrop` also catches results that are stored and then forgotten:
+/// if it is dropped before its contents were accessed through `Deref` or
+/// [`WarnOnDrop::into_inner`], it logs a warning with `log::warn!`.
+///
+/// The warning is only emitted in builds with `debug_assertions` enabled.
+/// In release builds the wrapper does nothing beyond holding the value.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_warn_on_drop;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = vec_string_to_static_str_warn_on_drop(&strings);
+/// assert_eq!(*static_strs, vec!["hello", "world"]);
+/// ```
+pub struct WarnOnDrop<T> {
+    value: Option<T>,
+    read: Cell<bool>,
+}
+
+impl<T> WarnOnDrop<T> {
+    /// Wraps `value`, which has not been read yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The leaked result to be guarded.
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Some(value),
+            read: Cell::new(false),
+        }
+    }
+
+    /// Unwraps the guarded value without emitting a warning.
+    ///
+    /// # Returns
+    ///
+    /// The value that was passed to [`WarnOnDrop::new`].
+    pub fn into_inner(mut self) -> T {
+        self.read.set(true);
+        self.value
+            .take()
+            .expect("value is only taken by into_inner")
+    }
+}
+
+impl<T> Deref for WarnOnDrop<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.read.set(true);
+        self.value
+            .as_ref()
+            .expect("value is only taken by into_inner")
+    }
+}
+
+impl<T> Drop for WarnOnDrop<T> {
+    fn drop(&mut self) {
+        if cfg!(debug_assertions) && !self.read.get() {
+            log::warn!(
+                "a leaked `{}` was dropped without being read; its memory is wasted",
+                core::any::type_name::<T>()
+            );
+        }
+    }
+}
+
+/// Converts a vector of `String`s into a vector of `&'static str`, warning
+/// in debug builds if the result is dropped without being read.
+///
+/// This behaves like [`vec_string_to_static_str`], but wraps the result in
+/// a [`WarnOnDrop`].
+///
+/// # Arguments
+///
+/// * `strings` - A slice of `String`s to be converted.
+///
+/// # Returns
+///
+/// A [`WarnOnDrop`] holding a vector of `&'static str` references to copies
+/// of the input strings.
+///
+/// # Example
+///
+/// ```
+/// use vec_string_to_static_str::vec_string_to_static_str_warn_on_drop;
+///
+/// let strings = vec![String::from("hello"), String::from("world")];
+/// let static_strs = vec_string_to_static_str_warn_on_drop(&strings).into_inner();
+/// assert_eq!(static_strs, vec!["hello", "world"]);
+/// ```
+#[must_use = "this leaks memory; discarding the result wastes it permanently"]
+#[cfg_attr(feature = "debug-leak-sites", track_caller)]
+pub fn vec_string_to_static_str_warn_on_drop(strings: &[String]) -> WarnOnDrop<Vec<&'static str>> {
+    WarnOnDrop::new(vec_string_to_static_str(strings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex, PoisonError};
+    use std::thread::{self, ThreadId};
+
+    /// Records warnings per thread, so that tests running in parallel only
+    /// see their own.
+    struct TestLogger {
+        warnings: Mutex<Vec<(ThreadId, String)>>,
+    }
+
+    impl log::Log for TestLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Warn
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                self.warnings
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push((thread::current().id(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: TestLogger = TestLogger {
+        warnings: Mutex::new(Vec::new()),
+    };
+
+    fn warnings_on_current_thread(f: impl FnOnce()) -> Vec<String> {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Warn);
+
+        f();
+
+        let id = thread::current().id();
+        LOGGER
+            .warnings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|(thread, _)| *thread == id)
+            .map(|(_, warning)| warning.clone())
+            .collect()
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn warn_on_drop_warns_when_unread() {
+        let strings = vec!["string_a".to_string()];
+
+        let actual = warnings_on_current_thread(|| {
+            drop(vec_string_to_static_str_warn_on_drop(&strings));
+        });
+
+        assert_eq!(1, actual.len());
+        assert!(actual[0].contains("dropped without being read"));
+    }
+
+    #[test]
+    fn warn_on_drop_silent_when_dereferenced() {
+        let strings = vec!["string_a".to_string()];
+
+        let actual = warnings_on_current_thread(|| {
+            let static_strs = vec_string_to_static_str_warn_on_drop(&strings);
+            assert_eq!(vec!["string_a"], *static_strs);
+        });
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn warn_on_drop_silent_after_into_inner() {
+        let strings = vec!["string_a".to_string()];
+
+        let actual = warnings_on_current_thread(|| {
+            let static_strs = vec_string_to_static_str_warn_on_drop(&strings).into_inner();
+            assert_eq!(vec!["string_a"], static_strs);
+        });
+
+        assert!(actual.is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+//! tests/cleanup.rs
+//!
+//! `free_all_leaked` frees everything the crate has leaked in the whole
+//! process, so it lives in its own binary with a single test. Running it
+//! under Miri without `-Zmiri-ignore-leaks` checks that nothing is left:
+//!
+//! ```text
+//! MIRIFLAGS="-Zmiri-strict-provenance" cargo +nightly miri test --features cleanup --test cleanup
+//! ```
+
+#![cfg(feature = "cleanup")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use vec_string_to_static_str::*;
+
+struct CountingAllocator;
+
+static DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[test]
+fn free_all_leaked_reclaims_every_leak() {
+    let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+    assert_eq!("string_a", str_to_static_str("string_a"));
+    assert_eq!(strings, vec_string_to_static_str(&strings));
+    assert_eq!(strings, vec_string_to_static_str_packed(&strings));
+    assert_eq!(strings, vec_string_to_static_slice(&strings));
+    let mut arena = LeakArena::with_capacity(16);
+    assert_eq!("string_a", arena.push("string_a"));
+    assert_eq!(strings, leak_vec_as_static_strs(strings.clone()));
+    // An empty string owns no allocation.
+    assert_eq!("", str_to_static_str(""));
+
+    let before = DEALLOCATIONS.load(Ordering::Relaxed);
+    // SAFETY: none of the references leaked above is used afterwards.
+    unsafe { free_all_leaked() };
+    let after = DEALLOCATIONS.load(Ordering::Relaxed);
+
+    // One string, two strings, the packed buffer, two strings and their
+    // table, the arena chunk, two strings and their vector, and finally the
+    // registry's own buffer.
+    assert_eq!(1 + 2 + 1 + 3 + 1 + 3 + 1, after - before);
+}
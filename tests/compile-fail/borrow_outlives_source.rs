@@ -0,0 +1,8 @@
+use vec_string_to_static_str::vec_string_as_strs;
+
+fn main() {
+    let strings = vec![String::from("hello"), String::from("world")];
+    let strs = vec_string_as_strs(&strings);
+    drop(strings);
+    println!("{strs:?}");
+}
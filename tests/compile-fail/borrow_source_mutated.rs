@@ -0,0 +1,8 @@
+use vec_string_to_static_str::vec_string_as_strs;
+
+fn main() {
+    let mut strings = vec![String::from("hello")];
+    let strs = vec_string_as_strs(&strings);
+    strings[0].push_str(" world");
+    println!("{strs:?}");
+}
@@ -0,0 +1,9 @@
+use vec_string_to_static_str::LeakGuard;
+
+fn main() {
+    let strings = vec![String::from("hello"), String::from("world")];
+    let guard = LeakGuard::new(&strings);
+    let strs = guard.as_static_strs();
+    drop(guard);
+    println!("{strs:?}");
+}
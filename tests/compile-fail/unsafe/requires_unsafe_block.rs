@@ -0,0 +1,7 @@
+use vec_string_to_static_str::unsafe_vec_string_to_static_str;
+
+fn main() {
+    let strings = vec![String::from("hello"), String::from("world")];
+    let strs = unsafe_vec_string_to_static_str(&strings);
+    println!("{strs:?}");
+}
@@ -0,0 +1,14 @@
+use vec_string_to_static_str::{vec_string_as_strs, LeakGuard};
+
+fn main() {
+    let strings = vec![String::from("hello"), String::from("world")];
+
+    let strs = vec_string_as_strs(&strings);
+    assert_eq!(strs, ["hello", "world"]);
+
+    let guard = LeakGuard::new(&strings);
+    assert_eq!(guard.as_static_strs(), ["hello", "world"]);
+    drop(guard);
+
+    drop(strings);
+}
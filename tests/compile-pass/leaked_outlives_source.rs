@@ -0,0 +1,12 @@
+use vec_string_to_static_str::{into_static_strs, vec_string_to_static_str};
+
+fn keep(_: Vec<&'static str>) {}
+
+fn main() {
+    let strings = vec![String::from("hello"), String::from("world")];
+    let strs = vec_string_to_static_str(&strings);
+    drop(strings);
+    keep(strs);
+
+    keep(into_static_strs(vec![String::from("owned")]));
+}
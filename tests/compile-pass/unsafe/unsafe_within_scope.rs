@@ -0,0 +1,8 @@
+use vec_string_to_static_str::unsafe_vec_string_to_static_str;
+
+fn main() {
+    let strings = vec![String::from("hello"), String::from("world")];
+    // SAFETY: `strings` outlives `strs`.
+    let strs = unsafe { unsafe_vec_string_to_static_str(&strings) };
+    assert_eq!(strs, ["hello", "world"]);
+}
@@ -0,0 +1,36 @@
+//! tests/global.rs
+//!
+//! The global interner is shared by the whole process, so tests that check
+//! its exact statistics live in their own binary.
+
+#![cfg(feature = "global")]
+
+use std::thread;
+
+use vec_string_to_static_str::*;
+
+#[test]
+fn global_intern_stats_across_threads() {
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            thread::spawn(move || {
+                let _ = global_intern_stats();
+                for j in 0..100 {
+                    let _ = global_intern(&format!("string_{}", (i + j) % 10));
+                }
+                let _ = global_intern_stats();
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let actual = global_intern_stats();
+
+    assert_eq!(800, actual.total);
+    assert_eq!(10, actual.unique);
+    assert_eq!(10 * "string_0".len(), actual.bytes);
+    assert_eq!(790 * "string_0".len(), actual.bytes_saved);
+}
@@ -0,0 +1,120 @@
+//! tests/leak_sites.rs
+//!
+//! The leak site registry is process-wide, so these tests live in their own
+//! binary and only look at the call sites they create themselves.
+
+#![cfg(feature = "debug-leak-sites")]
+
+use vec_string_to_static_str::*;
+
+fn bytes_leaked_at(line: u32) -> Vec<usize> {
+    leak_sites()
+        .into_iter()
+        .filter(|(location, _)| location.file() == file!() && location.line() == line)
+        .map(|(_, bytes)| bytes)
+        .collect()
+}
+
+#[test]
+fn leak_sites_records_distinct_call_sites() {
+    let strings = vec!["string_a".to_string(), "你好".to_string()];
+
+    let _ = vec_string_to_static_str(&strings);
+    let first_line = line!() - 1;
+    let _ = str_to_static_str("hello");
+    let second_line = line!() - 1;
+
+    assert_eq!(vec![8 + 6], bytes_leaked_at(first_line));
+    assert_eq!(vec![5], bytes_leaked_at(second_line));
+}
+
+#[test]
+fn leak_sites_accumulates_repeated_calls() {
+    for _ in 0..3 {
+        let _ = str_to_static_str("abc");
+    }
+    let line = line!() - 2;
+
+    assert_eq!(vec![9], bytes_leaked_at(line));
+}
+
+#[test]
+fn leak_sites_reports_caller_of_transforms() {
+    let strings = vec!["users".to_string()];
+
+    let _ = vec_string_to_static_str_prefixed(&strings, "/api/");
+    let line = line!() - 1;
+
+    assert_eq!(vec![10], bytes_leaked_at(line));
+}
+
+#[test]
+fn leak_sites_reports_caller_of_collection_converters() {
+    let pairs = vec![("key".to_string(), "value".to_string())];
+
+    let _ = vec_pairs_to_static_str(&pairs);
+    let line = line!() - 1;
+
+    assert_eq!(vec![3 + 5], bytes_leaked_at(line));
+}
+
+#[test]
+fn leak_sites_reports_caller_of_consuming_converters() {
+    let _ = into_static_strs(vec!["string_a".to_string()]);
+    let into_line = line!() - 1;
+    let _ = leak_vec_as_static_strs(vec!["string_b".to_string(), "c".to_string()]);
+    let vec_line = line!() - 1;
+    let iter = leak_iter(vec!["string_d".to_string()].into_iter());
+    let iter_line = line!() - 1;
+    let _: Vec<&'static str> = iter.collect();
+
+    assert_eq!(vec![8], bytes_leaked_at(into_line));
+    assert_eq!(vec![9], bytes_leaked_at(vec_line));
+    assert_eq!(vec![8], bytes_leaked_at(iter_line));
+}
+
+#[test]
+fn leak_sites_reports_packed_buffer() {
+    let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+    let _ = vec_string_to_static_str_packed(&strings);
+    let line = line!() - 1;
+
+    assert_eq!(vec![16], bytes_leaked_at(line));
+}
+
+#[test]
+fn leak_sites_reports_arena_chunks() {
+    let mut arena = LeakArena::with_capacity(16);
+    let chunk_line = line!() - 1;
+
+    let _ = arena.push("string_a");
+    let _ = arena.push("string_b");
+    let _ = arena.push("an oversized string");
+    let oversized_line = line!() - 1;
+
+    assert_eq!(vec![16], bytes_leaked_at(chunk_line));
+    assert_eq!(vec![19], bytes_leaked_at(oversized_line));
+}
+
+#[test]
+fn leak_sites_reports_caller_of_interner() {
+    let mut interner = Interner::new();
+
+    let _ = interner.intern("string_a");
+    let line = line!() - 1;
+    let _ = interner.intern("string_a");
+
+    assert_eq!(vec![8], bytes_leaked_at(line));
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn leak_sites_reports_caller_of_parallel_converter() {
+    let strings = vec!["string_a".to_string(); 100];
+
+    let _ = par_vec_string_to_static_str(&strings);
+    let line = line!() - 1;
+
+    assert_eq!(vec![800], bytes_leaked_at(line));
+}
@@ -0,0 +1,207 @@
+//! tests/metrics.rs
+//!
+//! The leak counters are process-wide, so these tests live in their own
+//! binary and take a lock to keep their measurements from overlapping.
+
+#![cfg(feature = "metrics")]
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use vec_string_to_static_str::*;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[test]
+fn vec_string_to_static_str_counts_leaked_bytes() {
+    let _lock = lock();
+    let strings = vec!["a".to_string(), "bc".to_string(), "你好".to_string()];
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let _ = vec_string_to_static_str(&strings);
+
+    assert_eq!(1 + 2 + 6, leaked_bytes() - bytes_before);
+    assert_eq!(3, leaked_count() - count_before);
+}
+
+#[test]
+fn into_static_strs_counts_leaked_bytes() {
+    let _lock = lock();
+    let strings = vec!["string_a".to_string(), "string_b".to_string()];
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let _ = into_static_strs(strings);
+
+    assert_eq!(16, leaked_bytes() - bytes_before);
+    assert_eq!(2, leaked_count() - count_before);
+}
+
+#[test]
+fn into_static_strs_counts_only_content_bytes() {
+    let _lock = lock();
+    let mut string = String::with_capacity(1000);
+    string.push_str("hi");
+    let bytes_before = leaked_bytes();
+
+    let _ = into_static_strs(vec![string]);
+
+    assert_eq!(2, leaked_bytes() - bytes_before);
+}
+
+#[test]
+fn empty_inputs_leak_nothing() {
+    let _lock = lock();
+    let strings: Vec<String> = Vec::new();
+    let mut arena = LeakArena::with_capacity(16);
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let _ = vec_string_to_static_str(&strings);
+    let _ = vec_string_to_static_slice(&strings);
+    let _ = leak_sorted_dedup(&strings);
+    let _ = into_static_strs(strings);
+    let _ = arena.push("");
+
+    assert_eq!(bytes_before, leaked_bytes());
+    assert_eq!(count_before, leaked_count());
+}
+
+#[test]
+fn vec_string_to_static_bytes_counts_leaked_bytes() {
+    let _lock = lock();
+    let strings = vec!["string_a".to_string(), "é".to_string()];
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let _ = vec_string_to_static_bytes(&strings);
+
+    assert_eq!(10, leaked_bytes() - bytes_before);
+    assert_eq!(2, leaked_count() - count_before);
+}
+
+#[test]
+fn vec_string_to_static_cstr_leaks_nothing_on_error() {
+    let _lock = lock();
+    let strings = vec!["string_a".to_string(), "string\0b".to_string()];
+    let bytes_before = leaked_bytes();
+
+    let actual = vec_string_to_static_cstr(&strings);
+
+    assert!(actual.is_err());
+    assert_eq!(bytes_before, leaked_bytes());
+}
+
+#[test]
+fn vec_string_to_static_str_packed_counts_leaked_bytes() {
+    let _lock = lock();
+    let strings = vec!["string_a".to_string(), "".to_string(), "b".to_string()];
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let _ = vec_string_to_static_str_packed(&strings);
+
+    assert_eq!(9, leaked_bytes() - bytes_before);
+    assert_eq!(3, leaked_count() - count_before);
+}
+
+#[test]
+fn vec_string_to_static_str_filtered_counts_only_kept_strings() {
+    let _lock = lock();
+    let strings = vec![
+        "".to_string(),
+        "a".to_string(),
+        "".to_string(),
+        "b".to_string(),
+    ];
+    let count_before = leaked_count();
+
+    let _ = vec_string_to_static_str_filtered(&strings, |s| !s.is_empty());
+
+    assert_eq!(2, leaked_count() - count_before);
+}
+
+#[test]
+fn leak_join_counts_one_leak() {
+    let _lock = lock();
+    let strings = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let _ = leak_join(&strings, ", ");
+
+    assert_eq!(7, leaked_bytes() - bytes_before);
+    assert_eq!(1, leaked_count() - count_before);
+}
+
+#[test]
+fn vec_string_as_strs_leaks_nothing() {
+    let _lock = lock();
+    let strings = vec!["string_a".to_string(), "string_b".to_string()];
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let strs = vec_string_as_strs(&strings);
+
+    assert_eq!(strings, strs);
+    assert_eq!(bytes_before, leaked_bytes());
+    assert_eq!(count_before, leaked_count());
+}
+
+#[test]
+fn leak_iter_counts_only_consumed_items() {
+    let _lock = lock();
+    let strings = std::iter::repeat_with(|| String::from("string_a"));
+    let count_before = leaked_count();
+
+    let _: Vec<&'static str> = leak_iter(strings).take(3).collect();
+
+    assert_eq!(3, leaked_count() - count_before);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn interner_counts_only_new_strings() {
+    let _lock = lock();
+    let mut interner = Interner::new();
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let _ = interner.intern("string_a");
+    let _ = interner.intern("string_a");
+    let _ = interner.intern("b");
+
+    assert_eq!(9, leaked_bytes() - bytes_before);
+    assert_eq!(2, leaked_count() - count_before);
+}
+
+#[test]
+fn leak_arena_counts_chunks() {
+    let _lock = lock();
+    let bytes_before = leaked_bytes();
+    let count_before = leaked_count();
+
+    let mut arena = LeakArena::with_capacity(16);
+    let _ = arena.push("string_a");
+    let _ = arena.push("string_b");
+    let _ = arena.push("string_c");
+
+    assert_eq!(32, leaked_bytes() - bytes_before);
+    assert_eq!(3, leaked_count() - count_before);
+}
+
+#[test]
+fn leak_guard_is_not_counted() {
+    let _lock = lock();
+    let strings = vec!["string_a".to_string()];
+    let bytes_before = leaked_bytes();
+
+    let guard = LeakGuard::new(&strings);
+    drop(guard);
+
+    assert_eq!(bytes_before, leaked_bytes());
+}
@@ -0,0 +1,107 @@
+//! tests/miri.rs
+//!
+//! Exercises every code path that relies on `unsafe`, in the way callers
+//! are documented to use it, so that running the suite under Miri checks
+//! them for undefined behavior:
+//!
+//! ```text
+//! MIRIFLAGS="-Zmiri-ignore-leaks -Zmiri-strict-provenance" cargo +nightly miri test --features unsafe,cleanup,global,metrics,serde,unicode,warn-on-drop
+//! ```
+//!
+//! Leaking is the purpose of this crate, so Miri's leak check is disabled.
+//! Strict provenance checks that no pointer is ever rebuilt from an
+//! address.
+
+use vec_string_to_static_str::*;
+
+#[test]
+fn leak_guard_reads_then_frees() {
+    let strings = vec!["string_a".to_string(), "".to_string(), "你好".to_string()];
+
+    let guard = LeakGuard::new(&strings);
+
+    assert_eq!(["string_a", "", "你好"], guard.as_static_strs());
+    drop(guard);
+}
+
+#[test]
+fn leak_arena_spans_chunks() {
+    let mut arena = LeakArena::with_capacity(8);
+
+    let actual = [
+        arena.push("string_a"),
+        arena.push("b"),
+        arena.push("a string larger than one chunk"),
+        arena.push("c"),
+    ];
+
+    assert_eq!(
+        ["string_a", "b", "a string larger than one chunk", "c"],
+        actual
+    );
+}
+
+#[test]
+fn packed_strings_share_buffer() {
+    let strings = vec!["string_a".to_string(), "你好".to_string()];
+
+    let (actual, spans) = vec_string_to_static_str_with_spans(&strings);
+
+    assert_eq!(strings, actual);
+    assert_eq!(vec![0..8, 8..14], spans);
+}
+
+#[test]
+fn leak_vec_as_static_strs_reads_original_buffers() {
+    let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+    let actual = leak_vec_as_static_strs(strings);
+
+    assert_eq!(vec!["string_a", "string_b"], actual);
+}
+
+#[test]
+#[cfg(feature = "cleanup")]
+fn unleak_frees_leaked_string() {
+    let leaked = str_to_static_str("string_a");
+    assert_eq!("string_a", leaked);
+
+    // SAFETY: `leaked` is not used again.
+    unsafe { unleak(leaked) };
+}
+
+#[test]
+#[cfg(feature = "cleanup")]
+fn unleak_all_frees_leaked_strings() {
+    let strings = vec!["string_a".to_string(), "".to_string()];
+    let leaked = vec_string_to_static_str(&strings);
+    assert_eq!(strings, leaked);
+
+    // SAFETY: every element came from `vec_string_to_static_str` and none
+    // of them is used again.
+    unsafe { unleak_all(leaked) };
+}
+
+#[test]
+#[cfg(feature = "unsafe")]
+fn unsafe_vec_string_to_static_str_within_lifetime() {
+    let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+    // SAFETY: `strings` outlives `actual`, which is dropped first.
+    let actual = unsafe { unsafe_vec_string_to_static_str(&strings) };
+
+    assert_eq!(vec!["string_a", "string_b"], actual);
+    drop(actual);
+    drop(strings);
+}
+
+#[test]
+#[cfg(feature = "unsafe")]
+fn unsafe_str_to_static_str_subslice_within_lifetime() {
+    let string = String::from("hello world");
+
+    // SAFETY: `string` outlives `actual`.
+    let actual = unsafe { unsafe_str_to_static_str(&string[6..]) };
+
+    assert_eq!("world", actual);
+}
@@ -0,0 +1,31 @@
+//! tests/no_std/src/lib.rs
+//!
+//! Builds against the crate with `std` disabled to guard `no_std` support.
+//! Run with `cargo build --manifest-path tests/no_std/Cargo.toml`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use vec_string_to_static_str::*;
+
+pub fn convert(strings: Vec<String>) -> Vec<&'static str> {
+    let mut strs = vec_string_to_static_str(&strings);
+    strs.extend(vec_string_to_static_str_packed(&strings));
+    strs.extend(static_strs!["literal"]);
+    strs.extend(into_static_strs(strings));
+    strs
+}
+
+pub fn total_len(strings: &[String]) -> usize {
+    // SAFETY: the references are only used while `strings` is borrowed.
+    let strs = unsafe { unsafe_vec_string_to_static_str(strings) };
+    strs.iter().map(|s| s.len()).sum()
+}
+
+pub fn leaked() -> (usize, usize) {
+    (leaked_bytes(), leaked_count())
+}
@@ -0,0 +1,112 @@
+//! tests/proptest.rs
+//!
+//! Property-based tests: every converter must preserve the length, order
+//! and content of arbitrary inputs, including empty, very long and unusual
+//! Unicode strings, and the interning converters must return
+//! pointer-identical strings for equal inputs.
+//!
+//! Each case leaks its input, and Miri would take far too long to run
+//! hundreds of cases, so these tests are skipped under it. proptest does
+//! not build for `wasm32-unknown-unknown`, so they are skipped there too.
+
+#![cfg(not(any(miri, target_arch = "wasm32")))]
+
+use proptest::prelude::*;
+use vec_string_to_static_str::*;
+
+/// Strings mixing the full range of `char`s with long runs and repeats.
+fn strings() -> impl Strategy<Value = Vec<String>> {
+    let string = prop_oneof![
+        any::<String>(),
+        "\\PC{0,1000}",
+        Just(String::new()),
+        Just("é\u{301}👩‍👩‍👧\u{0}\u{feff}".to_string()),
+    ];
+
+    prop::collection::vec(string, 0..32)
+}
+
+/// Asserts that two strings are the same leaked allocation.
+#[cfg(feature = "std")]
+fn assert_same_str(first: &str, second: &str) {
+    assert_eq!(first.as_ptr(), second.as_ptr());
+    assert_eq!(first.len(), second.len());
+}
+
+proptest! {
+    #[test]
+    fn copying_converters_preserve_content(strings in strings()) {
+        let borrowed: Vec<&str> = strings.iter().map(String::as_str).collect();
+
+        prop_assert_eq!(&strings, &vec_string_to_static_str(&strings));
+        prop_assert_eq!(&strings, &vec_string_to_static_slice(&strings).to_vec());
+        prop_assert_eq!(&strings, &to_static_strs(&strings));
+        prop_assert_eq!(&strings, &slice_str_to_static_str(&borrowed));
+        prop_assert_eq!(&strings, &vec_string_as_strs(&strings));
+        prop_assert_eq!(&strings, &vec_string_to_static_str_packed(&strings));
+        let boxed = vec_string_to_boxed_str(&strings);
+        prop_assert_eq!(&borrowed, &boxed.iter().map(|s| &**s).collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn consuming_converters_preserve_content(strings in strings()) {
+        prop_assert_eq!(&strings, &into_static_strs(strings.clone()));
+        prop_assert_eq!(&strings, &leak_vec_as_static_strs(strings.clone()));
+        prop_assert_eq!(&strings, &try_into_static_strs(strings.clone()).unwrap());
+        prop_assert_eq!(&strings, &leak_collect(strings.clone()));
+    }
+
+    #[test]
+    fn with_spans_index_into_concatenation(strings in strings()) {
+        let (strs, spans) = vec_string_to_static_str_with_spans(&strings);
+        let buffer = strings.concat();
+
+        prop_assert_eq!(&strings, &strs);
+        prop_assert_eq!(strings.len(), spans.len());
+        for (string, span) in strings.iter().zip(spans) {
+            prop_assert_eq!(string.as_str(), &buffer[span]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn intern_static_strs_shares_equal_strings(strings in strings()) {
+        let actual = intern_static_strs(&strings);
+
+        prop_assert_eq!(&strings, &actual);
+        for (i, first) in strings.iter().enumerate() {
+            for (j, second) in strings.iter().enumerate() {
+                if first == second {
+                    assert_same_str(actual[i], actual[j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn interner_shares_equal_strings(strings in strings()) {
+        let mut interner = Interner::new();
+        let first: Vec<&'static str> = strings.iter().map(|s| interner.intern(s).unwrap()).collect();
+        let second: Vec<&'static str> = strings.iter().map(|s| interner.intern(s).unwrap()).collect();
+
+        prop_assert_eq!(&strings, &first);
+        for (first, second) in first.iter().zip(&second) {
+            assert_same_str(first, second);
+        }
+        let mut distinct = strings.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        prop_assert_eq!(distinct.len(), interner.len());
+    }
+
+    #[test]
+    fn leak_sorted_dedup_is_sorted_and_distinct(strings in strings()) {
+        let actual = leak_sorted_dedup(&strings);
+
+        prop_assert!(actual.windows(2).all(|pair| pair[0] < pair[1]));
+        for string in &strings {
+            prop_assert!(actual.binary_search(&string.as_str()).is_ok());
+        }
+    }
+}
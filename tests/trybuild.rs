@@ -0,0 +1,26 @@
+//! tests/trybuild.rs
+//!
+//! Checks the lifetime contracts of the API at compile time: misuse in
+//! `tests/compile-fail` must be rejected and the patterns in
+//! `tests/compile-pass` must build. Regenerate the expected compiler output
+//! with `TRYBUILD=overwrite cargo test --all-features --test trybuild`.
+//!
+//! Miri and WebAssembly cannot spawn the compiler, so these tests are
+//! skipped there.
+
+#![cfg(not(any(miri, target_arch = "wasm32")))]
+
+#[test]
+fn lifetime_contracts() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/*.rs");
+    t.pass("tests/compile-pass/*.rs");
+}
+
+#[test]
+#[cfg(feature = "unsafe")]
+fn unsafe_contracts() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/unsafe/*.rs");
+    t.pass("tests/compile-pass/unsafe/*.rs");
+}
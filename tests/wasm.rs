@@ -0,0 +1,41 @@
+//! tests/wasm.rs
+//!
+//! Runs the core converters inside a WebAssembly instance. These tests only
+//! build for `wasm32` targets; run them with `wasm-pack test --node`.
+
+#![cfg(target_arch = "wasm32")]
+
+use vec_string_to_static_str::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn vec_string_to_static_str_outlives_input() {
+    let strings = vec!["string_a".to_string(), "string_b".to_string()];
+
+    let actual = vec_string_to_static_str(&strings);
+    drop(strings);
+
+    assert_eq!(vec!["string_a", "string_b"], actual);
+}
+
+#[wasm_bindgen_test]
+fn into_static_strs_leaks_in_place() {
+    let strings = vec!["string_a".to_string(), "日本語".to_string()];
+    let address = strings[1].as_ptr();
+
+    let actual = into_static_strs(strings);
+
+    assert_eq!(vec!["string_a", "日本語"], actual);
+    assert_eq!(address, actual[1].as_ptr());
+}
+
+#[wasm_bindgen_test]
+fn interner_deduplicates() {
+    let mut interner = Interner::new();
+
+    let first = interner.intern("string_a").unwrap();
+    let second = interner.intern(&String::from("string_a")).unwrap();
+
+    assert!(std::ptr::eq(first, second));
+    assert_eq!(1, interner.len());
+}